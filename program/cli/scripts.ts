@@ -40,6 +40,8 @@ import {
   fetchListingV2,
   safeFetchListingV2,
   placeBid,
+  TimerMode,
+  TimingMode,
 } from "../clients/generated/umi/src";
 import {
   fetchMint,
@@ -73,6 +75,8 @@ import {
 
 dotenv.config();
 
+const MS_IN_SEC = 1000;
+
 type Cluster = "mainnet-beta" | "devnet" | "testnet";
 
 /**
@@ -141,8 +145,11 @@ export const createAuctionListing = async (
     const price = BigInt(500000000);
     const bidIncrement = (price * BigInt(10)) / BigInt(10_000);
     const seed = generateRandomU64Seed(umi);
-    const durationInSlots = BigInt((30 * 60 * 1000) / 400); // 30 mins in slots
-    const timerExtensionInSlots = BigInt((30 * 1000) / 400); // 30 seconds in slots
+    // Listing times are unix timestamps, in seconds
+    // listings can't start in the past, leave room for the tx to land
+    const startTime = BigInt(Math.floor(Date.now() / MS_IN_SEC) + 10);
+    const duration = BigInt((30 * 60 * 1000) / MS_IN_SEC); // 30 mins
+    const timerExtension = BigInt((30 * 1000) / MS_IN_SEC); // 30 seconds
 
     const [listing] = getListingPDA(umi, marketplace, mint, seed);
     // `findAssociatedTokenPda` does not support off-curve :(
@@ -163,8 +170,6 @@ export const createAuctionListing = async (
       mint,
     });
 
-    const slot = await umi.rpc.getSlot();
-
     console.log("Creating Listing Acution", listing.toString(), "...");
 
     const accounts = {
@@ -182,11 +187,19 @@ export const createAuctionListing = async (
       tokenProgram: fromWeb3JsPublicKey(TOKEN_PROGRAM_ID), // this looks ugly
       sysvarInstructions: fromWeb3JsPublicKey(SYSVAR_INSTRUCTIONS_PUBKEY), // so does this ...
       seed,
-      bidIncrement,
-      timerExtensionInSlots,
-      startTimeInSlots: slot,
-      initialDurationInSlots: durationInSlots,
-      buyoutPrice: price,
+      params: {
+        bidCost: BigInt(10 ** 6),
+        bidIncrement,
+        timerExtensionInSlots: timerExtension,
+        startTimeInSlots: startTime,
+        initialDurationInSlots: duration,
+        timerMode: TimerMode.Cumulative,
+        timingMode: TimingMode.UnixTimestamp,
+        maxEndTimeInSlots: BigInt(0),
+        buyoutPrice: price,
+        reservePrice: BigInt(0),
+        solEscrow: false,
+      },
       amount: BigInt(1),
     };
 
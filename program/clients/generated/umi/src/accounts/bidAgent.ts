@@ -0,0 +1,192 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type BidAgent = Account<BidAgentAccountData>;
+
+export type BidAgentAccountData = {
+  discriminator: Uint8Array;
+  owner: PublicKey;
+  listing: PublicKey;
+  marketplace: PublicKey;
+  maxPrice: bigint;
+  maxBids: number;
+  bidsPlaced: number;
+  bidWindowInSlots: bigint;
+  deposited: bigint;
+  bump: number;
+  padding: Array<number>;
+  reserved: Array<number>;
+};
+
+export type BidAgentAccountDataArgs = {
+  owner: PublicKey;
+  listing: PublicKey;
+  marketplace: PublicKey;
+  maxPrice: number | bigint;
+  maxBids: number;
+  bidsPlaced: number;
+  bidWindowInSlots: number | bigint;
+  deposited: number | bigint;
+  bump: number;
+  padding: Array<number>;
+  reserved: Array<number>;
+};
+
+export function getBidAgentAccountDataSerializer(): Serializer<
+  BidAgentAccountDataArgs,
+  BidAgentAccountData
+> {
+  return mapSerializer<BidAgentAccountDataArgs, any, BidAgentAccountData>(
+    struct<BidAgentAccountData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['owner', publicKeySerializer()],
+        ['listing', publicKeySerializer()],
+        ['marketplace', publicKeySerializer()],
+        ['maxPrice', u64()],
+        ['maxBids', u32()],
+        ['bidsPlaced', u32()],
+        ['bidWindowInSlots', u64()],
+        ['deposited', u64()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['reserved', array(u8(), { size: 32 })],
+      ],
+      { description: 'BidAgentAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([223, 178, 99, 56, 168, 61, 86, 156]),
+    })
+  ) as Serializer<BidAgentAccountDataArgs, BidAgentAccountData>;
+}
+
+export function deserializeBidAgent(rawAccount: RpcAccount): BidAgent {
+  return deserializeAccount(rawAccount, getBidAgentAccountDataSerializer());
+}
+
+export async function fetchBidAgent(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<BidAgent> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'BidAgent');
+  return deserializeBidAgent(maybeAccount);
+}
+
+export async function safeFetchBidAgent(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<BidAgent | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeBidAgent(maybeAccount) : null;
+}
+
+export async function fetchAllBidAgent(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<BidAgent[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'BidAgent');
+    return deserializeBidAgent(maybeAccount);
+  });
+}
+
+export async function safeFetchAllBidAgent(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<BidAgent[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeBidAgent(maybeAccount as RpcAccount));
+}
+
+export function getBidAgentGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Uint8Array;
+      owner: PublicKey;
+      listing: PublicKey;
+      marketplace: PublicKey;
+      maxPrice: number | bigint;
+      maxBids: number;
+      bidsPlaced: number;
+      bidWindowInSlots: number | bigint;
+      deposited: number | bigint;
+      bump: number;
+      padding: Array<number>;
+      reserved: Array<number>;
+    }>({
+      discriminator: [0, bytes({ size: 8 })],
+      owner: [8, publicKeySerializer()],
+      listing: [40, publicKeySerializer()],
+      marketplace: [72, publicKeySerializer()],
+      maxPrice: [104, u64()],
+      maxBids: [112, u32()],
+      bidsPlaced: [116, u32()],
+      bidWindowInSlots: [120, u64()],
+      deposited: [128, u64()],
+      bump: [136, u8()],
+      padding: [137, array(u8(), { size: 7 })],
+      reserved: [144, array(u8(), { size: 32 })],
+    })
+    .deserializeUsing<BidAgent>((account) => deserializeBidAgent(account))
+    .whereField(
+      'discriminator',
+      new Uint8Array([223, 178, 99, 56, 168, 61, 86, 156])
+    );
+}
@@ -0,0 +1,166 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { BidRecord, BidRecordArgs, getBidRecordSerializer } from '../types';
+
+export type BidHistory = Account<BidHistoryAccountData>;
+
+export type BidHistoryAccountData = {
+  discriminator: Uint8Array;
+  listing: PublicKey;
+  head: number;
+  count: number;
+  bump: number;
+  padding: Array<number>;
+  bids: Array<BidRecord>;
+};
+
+export type BidHistoryAccountDataArgs = {
+  listing: PublicKey;
+  head: number;
+  count: number;
+  bump: number;
+  padding: Array<number>;
+  bids: Array<BidRecordArgs>;
+};
+
+export function getBidHistoryAccountDataSerializer(): Serializer<
+  BidHistoryAccountDataArgs,
+  BidHistoryAccountData
+> {
+  return mapSerializer<BidHistoryAccountDataArgs, any, BidHistoryAccountData>(
+    struct<BidHistoryAccountData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['listing', publicKeySerializer()],
+        ['head', u8()],
+        ['count', u8()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 5 })],
+        ['bids', array(getBidRecordSerializer(), { size: 10 })],
+      ],
+      { description: 'BidHistoryAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([199, 193, 173, 10, 170, 179, 35, 237]),
+    })
+  ) as Serializer<BidHistoryAccountDataArgs, BidHistoryAccountData>;
+}
+
+export function deserializeBidHistory(rawAccount: RpcAccount): BidHistory {
+  return deserializeAccount(rawAccount, getBidHistoryAccountDataSerializer());
+}
+
+export async function fetchBidHistory(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<BidHistory> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'BidHistory');
+  return deserializeBidHistory(maybeAccount);
+}
+
+export async function safeFetchBidHistory(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<BidHistory | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeBidHistory(maybeAccount) : null;
+}
+
+export async function fetchAllBidHistory(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<BidHistory[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'BidHistory');
+    return deserializeBidHistory(maybeAccount);
+  });
+}
+
+export async function safeFetchAllBidHistory(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<BidHistory[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeBidHistory(maybeAccount as RpcAccount));
+}
+
+export function getBidHistoryGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Uint8Array;
+      listing: PublicKey;
+      head: number;
+      count: number;
+      bump: number;
+      padding: Array<number>;
+      bids: Array<BidRecordArgs>;
+    }>({
+      discriminator: [0, bytes({ size: 8 })],
+      listing: [8, publicKeySerializer()],
+      head: [40, u8()],
+      count: [41, u8()],
+      bump: [42, u8()],
+      padding: [43, array(u8(), { size: 5 })],
+      bids: [48, array(getBidRecordSerializer(), { size: 10 })],
+    })
+    .deserializeUsing<BidHistory>((account) => deserializeBidHistory(account))
+    .whereField(
+      'discriminator',
+      new Uint8Array([199, 193, 173, 10, 170, 179, 35, 237])
+    );
+}
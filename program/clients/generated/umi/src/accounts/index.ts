@@ -6,6 +6,8 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './bidAgent';
+export * from './bidHistory';
 export * from './listingV2';
 export * from './marketplace';
 export * from './userAccount';
@@ -30,6 +30,14 @@ import {
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  TimerMode,
+  TimerModeArgs,
+  TimingMode,
+  TimingModeArgs,
+  getTimerModeSerializer,
+  getTimingModeSerializer,
+} from '../types';
 
 export type ListingV2 = Account<ListingV2AccountData>;
 
@@ -48,7 +56,15 @@ export type ListingV2AccountData = {
   buyoutPrice: bigint;
   seed: bigint;
   bump: number;
+  solEscrow: boolean;
+  bidVaultBump: number;
+  timerMode: TimerMode;
+  timingMode: TimingMode;
+  version: number;
   padding: Array<number>;
+  reservePrice: bigint;
+  maxEndTimeInSlots: bigint;
+  pauseCreditSnapshot: bigint;
   reserved: Array<number>;
 };
 
@@ -66,7 +82,15 @@ export type ListingV2AccountDataArgs = {
   buyoutPrice: number | bigint;
   seed: number | bigint;
   bump: number;
+  solEscrow: boolean;
+  bidVaultBump: number;
+  timerMode: TimerModeArgs;
+  timingMode: TimingModeArgs;
+  version: number;
   padding: Array<number>;
+  reservePrice: number | bigint;
+  maxEndTimeInSlots: number | bigint;
+  pauseCreditSnapshot: number | bigint;
   reserved: Array<number>;
 };
 
@@ -91,8 +115,16 @@ export function getListingV2AccountDataSerializer(): Serializer<
         ['buyoutPrice', u64()],
         ['seed', u64()],
         ['bump', u8()],
-        ['padding', array(u8(), { size: 6 })],
-        ['reserved', array(u8(), { size: 32 })],
+        ['solEscrow', bool()],
+        ['bidVaultBump', u8()],
+        ['timerMode', getTimerModeSerializer()],
+        ['timingMode', getTimingModeSerializer()],
+        ['version', u8()],
+        ['padding', array(u8(), { size: 1 })],
+        ['reservePrice', u64()],
+        ['maxEndTimeInSlots', u64()],
+        ['pauseCreditSnapshot', u64()],
+        ['reserved', array(u8(), { size: 8 })],
       ],
       { description: 'ListingV2AccountData' }
     ),
@@ -184,7 +216,15 @@ export function getListingV2GpaBuilder(
       buyoutPrice: number | bigint;
       seed: number | bigint;
       bump: number;
+      solEscrow: boolean;
+      bidVaultBump: number;
+      timerMode: TimerModeArgs;
+      timingMode: TimingModeArgs;
+      version: number;
       padding: Array<number>;
+      reservePrice: number | bigint;
+      maxEndTimeInSlots: number | bigint;
+      pauseCreditSnapshot: number | bigint;
       reserved: Array<number>;
     }>({
       discriminator: [0, bytes({ size: 8 })],
@@ -201,8 +241,16 @@ export function getListingV2GpaBuilder(
       buyoutPrice: [153, u64()],
       seed: [161, u64()],
       bump: [169, u8()],
-      padding: [170, array(u8(), { size: 6 })],
-      reserved: [176, array(u8(), { size: 32 })],
+      solEscrow: [170, bool()],
+      bidVaultBump: [171, u8()],
+      timerMode: [172, getTimerModeSerializer()],
+      timingMode: [173, getTimingModeSerializer()],
+      version: [174, u8()],
+      padding: [175, array(u8(), { size: 1 })],
+      reservePrice: [176, u64()],
+      maxEndTimeInSlots: [184, u64()],
+      pauseCreditSnapshot: [192, u64()],
+      reserved: [200, array(u8(), { size: 8 })],
     })
     .deserializeUsing<ListingV2>((account) => deserializeListingV2(account))
     .whereField(
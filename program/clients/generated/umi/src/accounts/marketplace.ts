@@ -22,15 +22,33 @@ import {
 import {
   Serializer,
   array,
+  bool,
   bytes,
   mapSerializer,
   publicKey as publicKeySerializer,
   string,
   struct,
   u16,
+  u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
-import { MintTier, MintTierArgs, getMintTierSerializer } from '../types';
+import {
+  BiddingPause,
+  BiddingPauseArgs,
+  HappyHour,
+  HappyHourArgs,
+  ListingBounds,
+  ListingBoundsArgs,
+  MintTier,
+  MintTierArgs,
+  PauseFlags,
+  PauseFlagsArgs,
+  getBiddingPauseSerializer,
+  getHappyHourSerializer,
+  getListingBoundsSerializer,
+  getMintTierSerializer,
+  getPauseFlagsSerializer,
+} from '../types';
 
 export type Marketplace = Account<MarketplaceAccountData>;
 
@@ -44,7 +62,17 @@ export type MarketplaceAccountData = {
   mintTiers: Array<MintTier>;
   bump: number;
   treasuryBump: number;
+  mintRequiresAdmin: boolean;
   padding: Array<number>;
+  happyHour: HappyHour;
+  settlementGraceInSlots: bigint;
+  minBidCost: bigint;
+  maxBidCost: bigint;
+  listingBounds: ListingBounds;
+  creator: PublicKey;
+  pendingAdmin: PublicKey;
+  paused: PauseFlags;
+  biddingPause: BiddingPause;
   reserved: Array<number>;
 };
 
@@ -57,7 +85,17 @@ export type MarketplaceAccountDataArgs = {
   mintTiers: Array<MintTierArgs>;
   bump: number;
   treasuryBump: number;
+  mintRequiresAdmin: boolean;
   padding: Array<number>;
+  happyHour: HappyHourArgs;
+  settlementGraceInSlots: number | bigint;
+  minBidCost: number | bigint;
+  maxBidCost: number | bigint;
+  listingBounds: ListingBoundsArgs;
+  creator: PublicKey;
+  pendingAdmin: PublicKey;
+  paused: PauseFlagsArgs;
+  biddingPause: BiddingPauseArgs;
   reserved: Array<number>;
 };
 
@@ -77,8 +115,18 @@ export function getMarketplaceAccountDataSerializer(): Serializer<
         ['mintTiers', array(getMintTierSerializer(), { size: 3 })],
         ['bump', u8()],
         ['treasuryBump', u8()],
-        ['padding', array(u8(), { size: 2 })],
-        ['reserved', array(u8(), { size: 64 })],
+        ['mintRequiresAdmin', bool()],
+        ['padding', array(u8(), { size: 1 })],
+        ['happyHour', getHappyHourSerializer()],
+        ['settlementGraceInSlots', u64()],
+        ['minBidCost', u64()],
+        ['maxBidCost', u64()],
+        ['listingBounds', getListingBoundsSerializer()],
+        ['creator', publicKeySerializer()],
+        ['pendingAdmin', publicKeySerializer()],
+        ['paused', getPauseFlagsSerializer()],
+        ['biddingPause', getBiddingPauseSerializer()],
+        ['reserved', array(u8(), { size: 12 })],
       ],
       { description: 'MarketplaceAccountData' }
     ),
@@ -165,7 +213,17 @@ export function getMarketplaceGpaBuilder(
       mintTiers: Array<MintTierArgs>;
       bump: number;
       treasuryBump: number;
+      mintRequiresAdmin: boolean;
       padding: Array<number>;
+      happyHour: HappyHourArgs;
+      settlementGraceInSlots: number | bigint;
+      minBidCost: number | bigint;
+      maxBidCost: number | bigint;
+      listingBounds: ListingBoundsArgs;
+      creator: PublicKey;
+      pendingAdmin: PublicKey;
+      paused: PauseFlagsArgs;
+      biddingPause: BiddingPauseArgs;
       reserved: Array<number>;
     }>({
       discriminator: [0, bytes({ size: 8 })],
@@ -177,8 +235,18 @@ export function getMarketplaceGpaBuilder(
       mintTiers: [null, array(getMintTierSerializer(), { size: 3 })],
       bump: [null, u8()],
       treasuryBump: [null, u8()],
-      padding: [null, array(u8(), { size: 2 })],
-      reserved: [null, array(u8(), { size: 64 })],
+      mintRequiresAdmin: [null, bool()],
+      padding: [null, array(u8(), { size: 1 })],
+      happyHour: [null, getHappyHourSerializer()],
+      settlementGraceInSlots: [null, u64()],
+      minBidCost: [null, u64()],
+      maxBidCost: [null, u64()],
+      listingBounds: [null, getListingBoundsSerializer()],
+      creator: [null, publicKeySerializer()],
+      pendingAdmin: [null, publicKeySerializer()],
+      paused: [null, getPauseFlagsSerializer()],
+      biddingPause: [null, getBiddingPauseSerializer()],
+      reserved: [null, array(u8(), { size: 12 })],
     })
     .deserializeUsing<Marketplace>((account) => deserializeMarketplace(account))
     .whereField(
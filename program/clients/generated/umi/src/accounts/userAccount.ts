@@ -9,6 +9,8 @@
 import {
   Account,
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   RpcAccount,
@@ -23,12 +25,16 @@ import {
   Serializer,
   array,
   bytes,
+  i64,
   mapSerializer,
+  option,
   publicKey as publicKeySerializer,
   struct,
   u32,
+  u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import { BidLimits, BidLimitsArgs, getBidLimitsSerializer } from '../types';
 
 export type UserAccount = Account<UserAccountAccountData>;
 
@@ -41,7 +47,13 @@ export type UserAccountAccountData = {
   totalAuctionsCreated: number;
   points: number;
   bump: number;
-  padding: Array<number>;
+  bidLimits: BidLimits;
+  limitWindowStart: bigint;
+  bidsInWindow: number;
+  spentInWindow: bigint;
+  pendingBidLimits: Option<BidLimits>;
+  excludedUntil: bigint;
+  totalAuctionsUnpaid: number;
   reserved: Array<number>;
 };
 
@@ -53,7 +65,13 @@ export type UserAccountAccountDataArgs = {
   totalAuctionsCreated: number;
   points: number;
   bump: number;
-  padding: Array<number>;
+  bidLimits: BidLimitsArgs;
+  limitWindowStart: number | bigint;
+  bidsInWindow: number;
+  spentInWindow: number | bigint;
+  pendingBidLimits: OptionOrNullable<BidLimitsArgs>;
+  excludedUntil: number | bigint;
+  totalAuctionsUnpaid: number;
   reserved: Array<number>;
 };
 
@@ -72,8 +90,14 @@ export function getUserAccountAccountDataSerializer(): Serializer<
         ['totalAuctionsCreated', u32()],
         ['points', u32()],
         ['bump', u8()],
-        ['padding', array(u8(), { size: 3 })],
-        ['reserved', array(u8(), { size: 32 })],
+        ['bidLimits', getBidLimitsSerializer()],
+        ['limitWindowStart', i64()],
+        ['bidsInWindow', u32()],
+        ['spentInWindow', u64()],
+        ['pendingBidLimits', option(getBidLimitsSerializer())],
+        ['excludedUntil', i64()],
+        ['totalAuctionsUnpaid', u32()],
+        ['reserved', array(u8(), { size: 2 })],
       ],
       { description: 'UserAccountAccountData' }
     ),
@@ -159,7 +183,13 @@ export function getUserAccountGpaBuilder(
       totalAuctionsCreated: number;
       points: number;
       bump: number;
-      padding: Array<number>;
+      bidLimits: BidLimitsArgs;
+      limitWindowStart: number | bigint;
+      bidsInWindow: number;
+      spentInWindow: number | bigint;
+      pendingBidLimits: OptionOrNullable<BidLimitsArgs>;
+      excludedUntil: number | bigint;
+      totalAuctionsUnpaid: number;
       reserved: Array<number>;
     }>({
       discriminator: [0, bytes({ size: 8 })],
@@ -170,8 +200,14 @@ export function getUserAccountGpaBuilder(
       totalAuctionsCreated: [52, u32()],
       points: [56, u32()],
       bump: [60, u8()],
-      padding: [61, array(u8(), { size: 3 })],
-      reserved: [64, array(u8(), { size: 32 })],
+      bidLimits: [61, getBidLimitsSerializer()],
+      limitWindowStart: [74, i64()],
+      bidsInWindow: [82, u32()],
+      spentInWindow: [86, u64()],
+      pendingBidLimits: [94, option(getBidLimitsSerializer())],
+      excludedUntil: [null, i64()],
+      totalAuctionsUnpaid: [null, u32()],
+      reserved: [null, array(u8(), { size: 2 })],
     })
     .deserializeUsing<UserAccount>((account) => deserializeUserAccount(account))
     .whereField(
@@ -240,6 +240,540 @@ export class InvalidMintCostError extends ProgramError {
 codeToErrorMap.set(0x177f, InvalidMintCostError);
 nameToErrorMap.set('InvalidMintCost', InvalidMintCostError);
 
+/** InsufficientTreasuryBalance: Insufficient treasury balance */
+export class InsufficientTreasuryBalanceError extends ProgramError {
+  override readonly name: string = 'InsufficientTreasuryBalance';
+
+  readonly code: number = 0x1780; // 6016
+
+  constructor(program: Program, cause?: Error) {
+    super('Insufficient treasury balance', program, cause);
+  }
+}
+codeToErrorMap.set(0x1780, InsufficientTreasuryBalanceError);
+nameToErrorMap.set(
+  'InsufficientTreasuryBalance',
+  InsufficientTreasuryBalanceError
+);
+
+/** BuyoutNotAvailable: Listing has no buyout price */
+export class BuyoutNotAvailableError extends ProgramError {
+  override readonly name: string = 'BuyoutNotAvailable';
+
+  readonly code: number = 0x1781; // 6017
+
+  constructor(program: Program, cause?: Error) {
+    super('Listing has no buyout price', program, cause);
+  }
+}
+codeToErrorMap.set(0x1781, BuyoutNotAvailableError);
+nameToErrorMap.set('BuyoutNotAvailable', BuyoutNotAvailableError);
+
+/** BidLimitReached: Bid limit for the current period has been reached */
+export class BidLimitReachedError extends ProgramError {
+  override readonly name: string = 'BidLimitReached';
+
+  readonly code: number = 0x1782; // 6018
+
+  constructor(program: Program, cause?: Error) {
+    super('Bid limit for the current period has been reached', program, cause);
+  }
+}
+codeToErrorMap.set(0x1782, BidLimitReachedError);
+nameToErrorMap.set('BidLimitReached', BidLimitReachedError);
+
+/** SpendLimitReached: Spending limit for the current period has been reached */
+export class SpendLimitReachedError extends ProgramError {
+  override readonly name: string = 'SpendLimitReached';
+
+  readonly code: number = 0x1783; // 6019
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Spending limit for the current period has been reached',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1783, SpendLimitReachedError);
+nameToErrorMap.set('SpendLimitReached', SpendLimitReachedError);
+
+/** UserSelfExcluded: User is self excluded */
+export class UserSelfExcludedError extends ProgramError {
+  override readonly name: string = 'UserSelfExcluded';
+
+  readonly code: number = 0x1784; // 6020
+
+  constructor(program: Program, cause?: Error) {
+    super('User is self excluded', program, cause);
+  }
+}
+codeToErrorMap.set(0x1784, UserSelfExcludedError);
+nameToErrorMap.set('UserSelfExcluded', UserSelfExcludedError);
+
+/** InvalidHappyHour: Invalid happy hour configuration */
+export class InvalidHappyHourError extends ProgramError {
+  override readonly name: string = 'InvalidHappyHour';
+
+  readonly code: number = 0x1785; // 6021
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid happy hour configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x1785, InvalidHappyHourError);
+nameToErrorMap.set('InvalidHappyHour', InvalidHappyHourError);
+
+/** InvalidBidAgent: Invalid bid agent configuration */
+export class InvalidBidAgentError extends ProgramError {
+  override readonly name: string = 'InvalidBidAgent';
+
+  readonly code: number = 0x1786; // 6022
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid bid agent configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x1786, InvalidBidAgentError);
+nameToErrorMap.set('InvalidBidAgent', InvalidBidAgentError);
+
+/** BidAgentMaxBidsReached: Bid agent has placed its maximum number of bids */
+export class BidAgentMaxBidsReachedError extends ProgramError {
+  override readonly name: string = 'BidAgentMaxBidsReached';
+
+  readonly code: number = 0x1787; // 6023
+
+  constructor(program: Program, cause?: Error) {
+    super('Bid agent has placed its maximum number of bids', program, cause);
+  }
+}
+codeToErrorMap.set(0x1787, BidAgentMaxBidsReachedError);
+nameToErrorMap.set('BidAgentMaxBidsReached', BidAgentMaxBidsReachedError);
+
+/** BidAgentMaxPriceReached: Bid agent max price would be exceeded */
+export class BidAgentMaxPriceReachedError extends ProgramError {
+  override readonly name: string = 'BidAgentMaxPriceReached';
+
+  readonly code: number = 0x1788; // 6024
+
+  constructor(program: Program, cause?: Error) {
+    super('Bid agent max price would be exceeded', program, cause);
+  }
+}
+codeToErrorMap.set(0x1788, BidAgentMaxPriceReachedError);
+nameToErrorMap.set('BidAgentMaxPriceReached', BidAgentMaxPriceReachedError);
+
+/** BidAgentOutsideBidWindow: Auction is outside of the bid agent bidding window */
+export class BidAgentOutsideBidWindowError extends ProgramError {
+  override readonly name: string = 'BidAgentOutsideBidWindow';
+
+  readonly code: number = 0x1789; // 6025
+
+  constructor(program: Program, cause?: Error) {
+    super('Auction is outside of the bid agent bidding window', program, cause);
+  }
+}
+codeToErrorMap.set(0x1789, BidAgentOutsideBidWindowError);
+nameToErrorMap.set('BidAgentOutsideBidWindow', BidAgentOutsideBidWindowError);
+
+/** BidAgentInsufficientDeposit: Bid agent deposit is too low for another bid */
+export class BidAgentInsufficientDepositError extends ProgramError {
+  override readonly name: string = 'BidAgentInsufficientDeposit';
+
+  readonly code: number = 0x178a; // 6026
+
+  constructor(program: Program, cause?: Error) {
+    super('Bid agent deposit is too low for another bid', program, cause);
+  }
+}
+codeToErrorMap.set(0x178a, BidAgentInsufficientDepositError);
+nameToErrorMap.set(
+  'BidAgentInsufficientDeposit',
+  BidAgentInsufficientDepositError
+);
+
+/** AdminSignatureRequired: Marketplace requires the admin to co-sign sBid purchases */
+export class AdminSignatureRequiredError extends ProgramError {
+  override readonly name: string = 'AdminSignatureRequired';
+
+  readonly code: number = 0x178b; // 6027
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Marketplace requires the admin to co-sign sBid purchases',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x178b, AdminSignatureRequiredError);
+nameToErrorMap.set('AdminSignatureRequired', AdminSignatureRequiredError);
+
+/** SettlementGracePeriodActive: Winner can still pay during the settlement grace period */
+export class SettlementGracePeriodActiveError extends ProgramError {
+  override readonly name: string = 'SettlementGracePeriodActive';
+
+  readonly code: number = 0x178c; // 6028
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Winner can still pay during the settlement grace period',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x178c, SettlementGracePeriodActiveError);
+nameToErrorMap.set(
+  'SettlementGracePeriodActive',
+  SettlementGracePeriodActiveError
+);
+
+/** SettlementGracePeriodExpired: Settlement grace period has expired */
+export class SettlementGracePeriodExpiredError extends ProgramError {
+  override readonly name: string = 'SettlementGracePeriodExpired';
+
+  readonly code: number = 0x178d; // 6029
+
+  constructor(program: Program, cause?: Error) {
+    super('Settlement grace period has expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x178d, SettlementGracePeriodExpiredError);
+nameToErrorMap.set(
+  'SettlementGracePeriodExpired',
+  SettlementGracePeriodExpiredError
+);
+
+/** InvalidWinnerAccount: Invalid winner user account */
+export class InvalidWinnerAccountError extends ProgramError {
+  override readonly name: string = 'InvalidWinnerAccount';
+
+  readonly code: number = 0x178e; // 6030
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid winner user account', program, cause);
+  }
+}
+codeToErrorMap.set(0x178e, InvalidWinnerAccountError);
+nameToErrorMap.set('InvalidWinnerAccount', InvalidWinnerAccountError);
+
+/** SolEscrowNotSupported: Bid agents are not supported on SOL escrow listings */
+export class SolEscrowNotSupportedError extends ProgramError {
+  override readonly name: string = 'SolEscrowNotSupported';
+
+  readonly code: number = 0x178f; // 6031
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Bid agents are not supported on SOL escrow listings',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x178f, SolEscrowNotSupportedError);
+nameToErrorMap.set('SolEscrowNotSupported', SolEscrowNotSupportedError);
+
+/** InvalidPreviousBidder: Invalid previous highest bidder account */
+export class InvalidPreviousBidderError extends ProgramError {
+  override readonly name: string = 'InvalidPreviousBidder';
+
+  readonly code: number = 0x1790; // 6032
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid previous highest bidder account', program, cause);
+  }
+}
+codeToErrorMap.set(0x1790, InvalidPreviousBidderError);
+nameToErrorMap.set('InvalidPreviousBidder', InvalidPreviousBidderError);
+
+/** InvalidReservePrice: Reserve price can't be above the buyout price */
+export class InvalidReservePriceError extends ProgramError {
+  override readonly name: string = 'InvalidReservePrice';
+
+  readonly code: number = 0x1791; // 6033
+
+  constructor(program: Program, cause?: Error) {
+    super("Reserve price can't be above the buyout price", program, cause);
+  }
+}
+codeToErrorMap.set(0x1791, InvalidReservePriceError);
+nameToErrorMap.set('InvalidReservePrice', InvalidReservePriceError);
+
+/** InvalidBidCost: Bid cost is outside of the marketplace bounds */
+export class InvalidBidCostError extends ProgramError {
+  override readonly name: string = 'InvalidBidCost';
+
+  readonly code: number = 0x1792; // 6034
+
+  constructor(program: Program, cause?: Error) {
+    super('Bid cost is outside of the marketplace bounds', program, cause);
+  }
+}
+codeToErrorMap.set(0x1792, InvalidBidCostError);
+nameToErrorMap.set('InvalidBidCost', InvalidBidCostError);
+
+/** InvalidBidCostBounds: Invalid bid cost bounds */
+export class InvalidBidCostBoundsError extends ProgramError {
+  override readonly name: string = 'InvalidBidCostBounds';
+
+  readonly code: number = 0x1793; // 6035
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid bid cost bounds', program, cause);
+  }
+}
+codeToErrorMap.set(0x1793, InvalidBidCostBoundsError);
+nameToErrorMap.set('InvalidBidCostBounds', InvalidBidCostBoundsError);
+
+/** InvalidTimerMode: Capped timer max end time is before the auction end time */
+export class InvalidTimerModeError extends ProgramError {
+  override readonly name: string = 'InvalidTimerMode';
+
+  readonly code: number = 0x1794; // 6036
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Capped timer max end time is before the auction end time',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1794, InvalidTimerModeError);
+nameToErrorMap.set('InvalidTimerMode', InvalidTimerModeError);
+
+/** InvalidBidIncrement: Bid increment is outside of the marketplace bounds */
+export class InvalidBidIncrementError extends ProgramError {
+  override readonly name: string = 'InvalidBidIncrement';
+
+  readonly code: number = 0x1795; // 6037
+
+  constructor(program: Program, cause?: Error) {
+    super('Bid increment is outside of the marketplace bounds', program, cause);
+  }
+}
+codeToErrorMap.set(0x1795, InvalidBidIncrementError);
+nameToErrorMap.set('InvalidBidIncrement', InvalidBidIncrementError);
+
+/** InvalidTimerExtension: Timer extension is outside of the marketplace bounds */
+export class InvalidTimerExtensionError extends ProgramError {
+  override readonly name: string = 'InvalidTimerExtension';
+
+  readonly code: number = 0x1796; // 6038
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Timer extension is outside of the marketplace bounds',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1796, InvalidTimerExtensionError);
+nameToErrorMap.set('InvalidTimerExtension', InvalidTimerExtensionError);
+
+/** InvalidListingDuration: Listing duration is above the marketplace maximum */
+export class InvalidListingDurationError extends ProgramError {
+  override readonly name: string = 'InvalidListingDuration';
+
+  readonly code: number = 0x1797; // 6039
+
+  constructor(program: Program, cause?: Error) {
+    super('Listing duration is above the marketplace maximum', program, cause);
+  }
+}
+codeToErrorMap.set(0x1797, InvalidListingDurationError);
+nameToErrorMap.set('InvalidListingDuration', InvalidListingDurationError);
+
+/** InvalidListingStartTime: Listing can't start before the current time */
+export class InvalidListingStartTimeError extends ProgramError {
+  override readonly name: string = 'InvalidListingStartTime';
+
+  readonly code: number = 0x1798; // 6040
+
+  constructor(program: Program, cause?: Error) {
+    super("Listing can't start before the current time", program, cause);
+  }
+}
+codeToErrorMap.set(0x1798, InvalidListingStartTimeError);
+nameToErrorMap.set('InvalidListingStartTime', InvalidListingStartTimeError);
+
+/** InvalidBuyoutPrice: Buyout price must be above the first bid */
+export class InvalidBuyoutPriceError extends ProgramError {
+  override readonly name: string = 'InvalidBuyoutPrice';
+
+  readonly code: number = 0x1799; // 6041
+
+  constructor(program: Program, cause?: Error) {
+    super('Buyout price must be above the first bid', program, cause);
+  }
+}
+codeToErrorMap.set(0x1799, InvalidBuyoutPriceError);
+nameToErrorMap.set('InvalidBuyoutPrice', InvalidBuyoutPriceError);
+
+/** InvalidListingBounds: Invalid listing bounds */
+export class InvalidListingBoundsError extends ProgramError {
+  override readonly name: string = 'InvalidListingBounds';
+
+  readonly code: number = 0x179a; // 6042
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid listing bounds', program, cause);
+  }
+}
+codeToErrorMap.set(0x179a, InvalidListingBoundsError);
+nameToErrorMap.set('InvalidListingBounds', InvalidListingBoundsError);
+
+/** InvalidPendingAdmin: Signer is not the pending admin */
+export class InvalidPendingAdminError extends ProgramError {
+  override readonly name: string = 'InvalidPendingAdmin';
+
+  readonly code: number = 0x179b; // 6043
+
+  constructor(program: Program, cause?: Error) {
+    super('Signer is not the pending admin', program, cause);
+  }
+}
+codeToErrorMap.set(0x179b, InvalidPendingAdminError);
+nameToErrorMap.set('InvalidPendingAdmin', InvalidPendingAdminError);
+
+/** ListingPaused: Listing is paused */
+export class ListingPausedError extends ProgramError {
+  override readonly name: string = 'ListingPaused';
+
+  readonly code: number = 0x179c; // 6044
+
+  constructor(program: Program, cause?: Error) {
+    super('Listing is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x179c, ListingPausedError);
+nameToErrorMap.set('ListingPaused', ListingPausedError);
+
+/** BiddingPaused: Bidding is paused */
+export class BiddingPausedError extends ProgramError {
+  override readonly name: string = 'BiddingPaused';
+
+  readonly code: number = 0x179d; // 6045
+
+  constructor(program: Program, cause?: Error) {
+    super('Bidding is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x179d, BiddingPausedError);
+nameToErrorMap.set('BiddingPaused', BiddingPausedError);
+
+/** MintingPaused: Minting is paused */
+export class MintingPausedError extends ProgramError {
+  override readonly name: string = 'MintingPaused';
+
+  readonly code: number = 0x179e; // 6046
+
+  constructor(program: Program, cause?: Error) {
+    super('Minting is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x179e, MintingPausedError);
+nameToErrorMap.set('MintingPaused', MintingPausedError);
+
+/** SettlementPaused: Settlement is paused */
+export class SettlementPausedError extends ProgramError {
+  override readonly name: string = 'SettlementPaused';
+
+  readonly code: number = 0x179f; // 6047
+
+  constructor(program: Program, cause?: Error) {
+    super('Settlement is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x179f, SettlementPausedError);
+nameToErrorMap.set('SettlementPaused', SettlementPausedError);
+
+/** InvalidMarketplaceFee: Marketplace fee is above the maximum */
+export class InvalidMarketplaceFeeError extends ProgramError {
+  override readonly name: string = 'InvalidMarketplaceFee';
+
+  readonly code: number = 0x17a0; // 6048
+
+  constructor(program: Program, cause?: Error) {
+    super('Marketplace fee is above the maximum', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a0, InvalidMarketplaceFeeError);
+nameToErrorMap.set('InvalidMarketplaceFee', InvalidMarketplaceFeeError);
+
+/** InvalidMintTiers: Each mint tier must be set exactly once */
+export class InvalidMintTiersError extends ProgramError {
+  override readonly name: string = 'InvalidMintTiers';
+
+  readonly code: number = 0x17a1; // 6049
+
+  constructor(program: Program, cause?: Error) {
+    super('Each mint tier must be set exactly once', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a1, InvalidMintTiersError);
+nameToErrorMap.set('InvalidMintTiers', InvalidMintTiersError);
+
+/** InvalidMintTierAmount: Mint tier amount and cost must be above zero */
+export class InvalidMintTierAmountError extends ProgramError {
+  override readonly name: string = 'InvalidMintTierAmount';
+
+  readonly code: number = 0x17a2; // 6050
+
+  constructor(program: Program, cause?: Error) {
+    super('Mint tier amount and cost must be above zero', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a2, InvalidMintTierAmountError);
+nameToErrorMap.set('InvalidMintTierAmount', InvalidMintTierAmountError);
+
+/** InvalidMintTierBonus: Mint tier bonus is above the maximum */
+export class InvalidMintTierBonusError extends ProgramError {
+  override readonly name: string = 'InvalidMintTierBonus';
+
+  readonly code: number = 0x17a3; // 6051
+
+  constructor(program: Program, cause?: Error) {
+    super('Mint tier bonus is above the maximum', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a3, InvalidMintTierBonusError);
+nameToErrorMap.set('InvalidMintTierBonus', InvalidMintTierBonusError);
+
+/** MissingBidHistory: Listing has a bid history which must be provided */
+export class MissingBidHistoryError extends ProgramError {
+  override readonly name: string = 'MissingBidHistory';
+
+  readonly code: number = 0x17a4; // 6052
+
+  constructor(program: Program, cause?: Error) {
+    super('Listing has a bid history which must be provided', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a4, MissingBidHistoryError);
+nameToErrorMap.set('MissingBidHistory', MissingBidHistoryError);
+
+/** MissingBidVault: Listing escrows bids in a bid vault which must be provided */
+export class MissingBidVaultError extends ProgramError {
+  override readonly name: string = 'MissingBidVault';
+
+  readonly code: number = 0x17a5; // 6053
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Listing escrows bids in a bid vault which must be provided',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a5, MissingBidVaultError);
+nameToErrorMap.set('MissingBidVault', MissingBidVaultError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -0,0 +1,150 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CancelBidAgentInstructionAccounts = {
+  owner: Signer;
+  bidAgent: PublicKey | Pda;
+  marketplace: PublicKey | Pda;
+  sbidMint: PublicKey | Pda;
+  ownerSbidAta?: PublicKey | Pda;
+  tokenProgram: PublicKey | Pda;
+};
+
+// Data.
+export type CancelBidAgentInstructionData = { discriminator: Uint8Array };
+
+export type CancelBidAgentInstructionDataArgs = {};
+
+export function getCancelBidAgentInstructionDataSerializer(): Serializer<
+  CancelBidAgentInstructionDataArgs,
+  CancelBidAgentInstructionData
+> {
+  return mapSerializer<
+    CancelBidAgentInstructionDataArgs,
+    any,
+    CancelBidAgentInstructionData
+  >(
+    struct<CancelBidAgentInstructionData>(
+      [['discriminator', bytes({ size: 8 })]],
+      { description: 'CancelBidAgentInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([96, 67, 78, 139, 118, 145, 150, 231]),
+    })
+  ) as Serializer<
+    CancelBidAgentInstructionDataArgs,
+    CancelBidAgentInstructionData
+  >;
+}
+
+// Instruction.
+export function cancelBidAgent(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: CancelBidAgentInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    owner: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.owner ?? null,
+    },
+    bidAgent: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.bidAgent ?? null,
+    },
+    marketplace: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.marketplace ?? null,
+    },
+    sbidMint: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.sbidMint ?? null,
+    },
+    ownerSbidAta: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.ownerSbidAta ?? null,
+    },
+    tokenProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.ownerSbidAta.value) {
+    resolvedAccounts.ownerSbidAta.value = context.eddsa.findPda(programId, [
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.owner.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.tokenProgram.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.sbidMint.value)
+      ),
+    ]);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCancelBidAgentInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
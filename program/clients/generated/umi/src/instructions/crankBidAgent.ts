@@ -0,0 +1,147 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CrankBidAgentInstructionAccounts = {
+  cranker: Signer;
+  bidAgent: PublicKey | Pda;
+  userAccount: PublicKey | Pda;
+  listing: PublicKey | Pda;
+  bidHistory?: PublicKey | Pda;
+  marketplace: PublicKey | Pda;
+};
+
+// Data.
+export type CrankBidAgentInstructionData = { discriminator: Uint8Array };
+
+export type CrankBidAgentInstructionDataArgs = {};
+
+export function getCrankBidAgentInstructionDataSerializer(): Serializer<
+  CrankBidAgentInstructionDataArgs,
+  CrankBidAgentInstructionData
+> {
+  return mapSerializer<
+    CrankBidAgentInstructionDataArgs,
+    any,
+    CrankBidAgentInstructionData
+  >(
+    struct<CrankBidAgentInstructionData>(
+      [['discriminator', bytes({ size: 8 })]],
+      { description: 'CrankBidAgentInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([108, 223, 110, 60, 70, 86, 183, 26]),
+    })
+  ) as Serializer<
+    CrankBidAgentInstructionDataArgs,
+    CrankBidAgentInstructionData
+  >;
+}
+
+// Instruction.
+export function crankBidAgent(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: CrankBidAgentInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    cranker: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.cranker ?? null,
+    },
+    bidAgent: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.bidAgent ?? null,
+    },
+    userAccount: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.userAccount ?? null,
+    },
+    listing: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.listing ?? null,
+    },
+    bidHistory: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.bidHistory ?? null,
+    },
+    marketplace: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.marketplace ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.bidHistory.value) {
+    resolvedAccounts.bidHistory.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 104, 105, 115, 116, 111, 114, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+    ]);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCrankBidAgentInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
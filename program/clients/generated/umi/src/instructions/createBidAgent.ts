@@ -0,0 +1,226 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CreateBidAgentInstructionAccounts = {
+  owner: Signer;
+  userAccount?: PublicKey | Pda;
+  bidAgent?: PublicKey | Pda;
+  listing: PublicKey | Pda;
+  marketplace: PublicKey | Pda;
+  sbidMint: PublicKey | Pda;
+  ownerSbidAta?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram: PublicKey | Pda;
+};
+
+// Data.
+export type CreateBidAgentInstructionData = {
+  discriminator: Uint8Array;
+  maxPrice: bigint;
+  maxBids: number;
+  bidWindowInSlots: bigint;
+  deposit: bigint;
+};
+
+export type CreateBidAgentInstructionDataArgs = {
+  maxPrice: number | bigint;
+  maxBids: number;
+  bidWindowInSlots: number | bigint;
+  deposit: number | bigint;
+};
+
+export function getCreateBidAgentInstructionDataSerializer(): Serializer<
+  CreateBidAgentInstructionDataArgs,
+  CreateBidAgentInstructionData
+> {
+  return mapSerializer<
+    CreateBidAgentInstructionDataArgs,
+    any,
+    CreateBidAgentInstructionData
+  >(
+    struct<CreateBidAgentInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['maxPrice', u64()],
+        ['maxBids', u32()],
+        ['bidWindowInSlots', u64()],
+        ['deposit', u64()],
+      ],
+      { description: 'CreateBidAgentInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([81, 169, 13, 67, 134, 95, 146, 163]),
+    })
+  ) as Serializer<
+    CreateBidAgentInstructionDataArgs,
+    CreateBidAgentInstructionData
+  >;
+}
+
+// Args.
+export type CreateBidAgentInstructionArgs = CreateBidAgentInstructionDataArgs;
+
+// Instruction.
+export function createBidAgent(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: CreateBidAgentInstructionAccounts & CreateBidAgentInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    owner: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.owner ?? null,
+    },
+    userAccount: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.userAccount ?? null,
+    },
+    bidAgent: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.bidAgent ?? null,
+    },
+    listing: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.listing ?? null,
+    },
+    marketplace: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.marketplace ?? null,
+    },
+    sbidMint: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.sbidMint ?? null,
+    },
+    ownerSbidAta: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.ownerSbidAta ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CreateBidAgentInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.userAccount.value) {
+    resolvedAccounts.userAccount.value = context.eddsa.findPda(programId, [
+      bytes().serialize(new Uint8Array([117, 115, 101, 114])),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.marketplace.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.owner.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.bidAgent.value) {
+    resolvedAccounts.bidAgent.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 97, 103, 101, 110, 116])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.owner.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.ownerSbidAta.value) {
+    resolvedAccounts.ownerSbidAta.value = context.eddsa.findPda(programId, [
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.owner.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.tokenProgram.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.sbidMint.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreateBidAgentInstructionDataSerializer().serialize(
+    resolvedArgs as CreateBidAgentInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
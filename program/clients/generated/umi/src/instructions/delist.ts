@@ -0,0 +1,301 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type DelistInstructionAccounts = {
+  seller: Signer;
+  sellerAta?: PublicKey | Pda;
+  mint: PublicKey | Pda;
+  listing: PublicKey | Pda;
+  bidHistory?: PublicKey | Pda;
+  bidVault?: PublicKey | Pda;
+  escrow?: PublicKey | Pda;
+  metadata?: PublicKey | Pda;
+  masterEdition?: PublicKey | Pda;
+  marketplace: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  metadataProgram?: PublicKey | Pda;
+  sysvarInstructions: PublicKey | Pda;
+};
+
+// Data.
+export type DelistInstructionData = {
+  discriminator: Uint8Array;
+  amount: bigint;
+};
+
+export type DelistInstructionDataArgs = { amount: number | bigint };
+
+export function getDelistInstructionDataSerializer(): Serializer<
+  DelistInstructionDataArgs,
+  DelistInstructionData
+> {
+  return mapSerializer<DelistInstructionDataArgs, any, DelistInstructionData>(
+    struct<DelistInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['amount', u64()],
+      ],
+      { description: 'DelistInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([55, 136, 205, 107, 107, 173, 4, 31]),
+    })
+  ) as Serializer<DelistInstructionDataArgs, DelistInstructionData>;
+}
+
+// Args.
+export type DelistInstructionArgs = DelistInstructionDataArgs;
+
+// Instruction.
+export function delist(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: DelistInstructionAccounts & DelistInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    seller: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.seller ?? null,
+    },
+    sellerAta: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.sellerAta ?? null,
+    },
+    mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
+    listing: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.listing ?? null,
+    },
+    bidHistory: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.bidHistory ?? null,
+    },
+    bidVault: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.bidVault ?? null,
+    },
+    escrow: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.escrow ?? null,
+    },
+    metadata: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.metadata ?? null,
+    },
+    masterEdition: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.masterEdition ?? null,
+    },
+    marketplace: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.marketplace ?? null,
+    },
+    associatedTokenProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    metadataProgram: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.metadataProgram ?? null,
+    },
+    sysvarInstructions: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.sysvarInstructions ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: DelistInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.sellerAta.value) {
+    resolvedAccounts.sellerAta.value = context.eddsa.findPda(programId, [
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.seller.value)
+      ),
+      bytes().serialize(
+        new Uint8Array([
+          6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
+          121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126,
+          255, 0, 169,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.mint.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.bidHistory.value) {
+    resolvedAccounts.bidHistory.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 104, 105, 115, 116, 111, 114, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.bidVault.value) {
+    resolvedAccounts.bidVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 118, 97, 117, 108, 116])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.escrow.value) {
+    resolvedAccounts.escrow.value = context.eddsa.findPda(programId, [
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+      bytes().serialize(
+        new Uint8Array([
+          6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
+          121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126,
+          255, 0, 169,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.mint.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.metadataProgram.value) {
+    resolvedAccounts.metadataProgram.value = context.programs.getPublicKey(
+      'metadataProgram',
+      'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+    );
+    resolvedAccounts.metadataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.metadata.value) {
+    resolvedAccounts.metadata.value = context.eddsa.findPda(programId, [
+      bytes().serialize(new Uint8Array([109, 101, 116, 97, 100, 97, 116, 97])),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.metadataProgram.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.mint.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.masterEdition.value) {
+    resolvedAccounts.masterEdition.value = context.eddsa.findPda(programId, [
+      bytes().serialize(new Uint8Array([109, 101, 116, 97, 100, 97, 116, 97])),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.metadataProgram.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.mint.value)
+      ),
+      bytes().serialize(new Uint8Array([101, 100, 105, 116, 105, 111, 110])),
+    ]);
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value =
+      context.programs.getPublicKey(
+        'associatedTokenProgram',
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+      );
+    resolvedAccounts.associatedTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'tokenProgram',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getDelistInstructionDataSerializer().serialize(
+    resolvedArgs as DelistInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -32,13 +32,15 @@ import {
 // Accounts.
 export type EndListingInstructionAccounts = {
   user: Signer;
-  admin: Signer;
   userAccount?: PublicKey | Pda;
   seller: PublicKey | Pda;
   userAta?: PublicKey | Pda;
   mint: PublicKey | Pda;
   collection: PublicKey | Pda;
   listing: PublicKey | Pda;
+  bidHistory?: PublicKey | Pda;
+  bidVault?: PublicKey | Pda;
+  highestBidder?: PublicKey | Pda;
   escrow?: PublicKey | Pda;
   metadata?: PublicKey | Pda;
   masterEdition?: PublicKey | Pda;
@@ -99,84 +101,94 @@ export function endListing(
   // Accounts.
   const resolvedAccounts = {
     user: { index: 0, isWritable: true as boolean, value: input.user ?? null },
-    admin: {
-      index: 1,
-      isWritable: false as boolean,
-      value: input.admin ?? null,
-    },
     userAccount: {
-      index: 2,
+      index: 1,
       isWritable: true as boolean,
       value: input.userAccount ?? null,
     },
     seller: {
-      index: 3,
+      index: 2,
       isWritable: true as boolean,
       value: input.seller ?? null,
     },
     userAta: {
-      index: 4,
+      index: 3,
       isWritable: true as boolean,
       value: input.userAta ?? null,
     },
-    mint: { index: 5, isWritable: false as boolean, value: input.mint ?? null },
+    mint: { index: 4, isWritable: false as boolean, value: input.mint ?? null },
     collection: {
-      index: 6,
+      index: 5,
       isWritable: false as boolean,
       value: input.collection ?? null,
     },
     listing: {
-      index: 7,
+      index: 6,
       isWritable: true as boolean,
       value: input.listing ?? null,
     },
-    escrow: {
+    bidHistory: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.bidHistory ?? null,
+    },
+    bidVault: {
       index: 8,
       isWritable: true as boolean,
+      value: input.bidVault ?? null,
+    },
+    highestBidder: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.highestBidder ?? null,
+    },
+    escrow: {
+      index: 10,
+      isWritable: true as boolean,
       value: input.escrow ?? null,
     },
     metadata: {
-      index: 9,
+      index: 11,
       isWritable: true as boolean,
       value: input.metadata ?? null,
     },
     masterEdition: {
-      index: 10,
+      index: 12,
       isWritable: false as boolean,
       value: input.masterEdition ?? null,
     },
     marketplace: {
-      index: 11,
+      index: 13,
       isWritable: true as boolean,
       value: input.marketplace ?? null,
     },
     treasury: {
-      index: 12,
+      index: 14,
       isWritable: true as boolean,
       value: input.treasury ?? null,
     },
     associatedTokenProgram: {
-      index: 13,
+      index: 15,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
     systemProgram: {
-      index: 14,
+      index: 16,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     tokenProgram: {
-      index: 15,
+      index: 17,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     metadataProgram: {
-      index: 16,
+      index: 18,
       isWritable: false as boolean,
       value: input.metadataProgram ?? null,
     },
     sysvarInstructions: {
-      index: 17,
+      index: 19,
       isWritable: false as boolean,
       value: input.sysvarInstructions ?? null,
     },
@@ -214,6 +226,26 @@ export function endListing(
       ),
     ]);
   }
+  if (!resolvedAccounts.bidHistory.value) {
+    resolvedAccounts.bidHistory.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 104, 105, 115, 116, 111, 114, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.bidVault.value) {
+    resolvedAccounts.bidVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 118, 97, 117, 108, 116])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+    ]);
+  }
   if (!resolvedAccounts.escrow.value) {
     resolvedAccounts.escrow.value = context.eddsa.findPda(programId, [
       publicKeySerializer().serialize(
@@ -6,10 +6,31 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './acceptAdmin';
+export * from './cancelBidAgent';
+export * from './crankBidAgent';
+export * from './createBidAgent';
+export * from './delist';
 export * from './endListing';
 export * from './initialize';
 export * from './initializeUser';
 export * from './list';
+export * from './migrateMarketplace';
+export * from './migrateUserAccount';
 export * from './mintBidToken';
 export * from './placeBid';
+export * from './proposeAdmin';
+export * from './purchase';
+export * from './selfExclude';
+export * from './setBidLimits';
+export * from './setPause';
+export * from './settleListing';
+export * from './updateMarketplaceBidCostBounds';
+export * from './updateMarketplaceFee';
+export * from './updateMarketplaceHappyHour';
+export * from './updateMarketplaceListingBounds';
+export * from './updateMarketplaceMintGate';
 export * from './updateMarketplaceMintTiers';
+export * from './updateMarketplaceSettlementGrace';
+export * from './updateMarketplaceTokenMetadata';
+export * from './withdrawTreasury';
@@ -29,6 +29,7 @@ import {
   expectSome,
   getAccountMetasAndSigners,
 } from '../shared';
+import { ListParams, ListParamsArgs, getListParamsSerializer } from '../types';
 
 // Accounts.
 export type ListInstructionAccounts = {
@@ -36,6 +37,8 @@ export type ListInstructionAccounts = {
   admin: Signer;
   userAccount?: PublicKey | Pda;
   listing?: PublicKey | Pda;
+  bidHistory?: PublicKey | Pda;
+  bidVault?: PublicKey | Pda;
   marketplace: PublicKey | Pda;
   mint: PublicKey | Pda;
   collection: PublicKey | Pda;
@@ -54,21 +57,13 @@ export type ListInstructionAccounts = {
 export type ListInstructionData = {
   discriminator: Uint8Array;
   seed: bigint;
-  bidIncrement: bigint;
-  timerExtensionInSlots: bigint;
-  startTimeInSlots: bigint;
-  initialDurationInSlots: bigint;
-  buyoutPrice: bigint;
+  params: ListParams;
   amount: bigint;
 };
 
 export type ListInstructionDataArgs = {
   seed: number | bigint;
-  bidIncrement: number | bigint;
-  timerExtensionInSlots: number | bigint;
-  startTimeInSlots: number | bigint;
-  initialDurationInSlots: number | bigint;
-  buyoutPrice: number | bigint;
+  params: ListParamsArgs;
   amount: number | bigint;
 };
 
@@ -81,11 +76,7 @@ export function getListInstructionDataSerializer(): Serializer<
       [
         ['discriminator', bytes({ size: 8 })],
         ['seed', u64()],
-        ['bidIncrement', u64()],
-        ['timerExtensionInSlots', u64()],
-        ['startTimeInSlots', u64()],
-        ['initialDurationInSlots', u64()],
-        ['buyoutPrice', u64()],
+        ['params', getListParamsSerializer()],
         ['amount', u64()],
       ],
       { description: 'ListInstructionData' }
@@ -133,59 +124,69 @@ export function list(
       isWritable: true as boolean,
       value: input.listing ?? null,
     },
-    marketplace: {
+    bidHistory: {
       index: 4,
       isWritable: true as boolean,
+      value: input.bidHistory ?? null,
+    },
+    bidVault: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.bidVault ?? null,
+    },
+    marketplace: {
+      index: 6,
+      isWritable: true as boolean,
       value: input.marketplace ?? null,
     },
-    mint: { index: 5, isWritable: false as boolean, value: input.mint ?? null },
+    mint: { index: 7, isWritable: false as boolean, value: input.mint ?? null },
     collection: {
-      index: 6,
+      index: 8,
       isWritable: false as boolean,
       value: input.collection ?? null,
     },
     sellerAta: {
-      index: 7,
+      index: 9,
       isWritable: true as boolean,
       value: input.sellerAta ?? null,
     },
     escrow: {
-      index: 8,
+      index: 10,
       isWritable: true as boolean,
       value: input.escrow ?? null,
     },
     metadata: {
-      index: 9,
+      index: 11,
       isWritable: true as boolean,
       value: input.metadata ?? null,
     },
     masterEdition: {
-      index: 10,
+      index: 12,
       isWritable: false as boolean,
       value: input.masterEdition ?? null,
     },
     associatedTokenProgram: {
-      index: 11,
+      index: 13,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
     systemProgram: {
-      index: 12,
+      index: 14,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     tokenProgram: {
-      index: 13,
+      index: 15,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     metadataProgram: {
-      index: 14,
+      index: 16,
       isWritable: false as boolean,
       value: input.metadataProgram ?? null,
     },
     sysvarInstructions: {
-      index: 15,
+      index: 17,
       isWritable: false as boolean,
       value: input.sysvarInstructions ?? null,
     },
@@ -218,6 +219,26 @@ export function list(
       u64().serialize(expectSome(resolvedArgs.seed)),
     ]);
   }
+  if (!resolvedAccounts.bidHistory.value) {
+    resolvedAccounts.bidHistory.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 104, 105, 115, 116, 111, 114, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.bidVault.value) {
+    resolvedAccounts.bidVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 118, 97, 117, 108, 116])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+    ]);
+  }
   if (!resolvedAccounts.sellerAta.value) {
     resolvedAccounts.sellerAta.value = context.eddsa.findPda(programId, [
       publicKeySerializer().serialize(
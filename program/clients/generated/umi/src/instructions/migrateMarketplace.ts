@@ -0,0 +1,124 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigrateMarketplaceInstructionAccounts = {
+  admin: Signer;
+  marketplace: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MigrateMarketplaceInstructionData = { discriminator: Uint8Array };
+
+export type MigrateMarketplaceInstructionDataArgs = {};
+
+export function getMigrateMarketplaceInstructionDataSerializer(): Serializer<
+  MigrateMarketplaceInstructionDataArgs,
+  MigrateMarketplaceInstructionData
+> {
+  return mapSerializer<
+    MigrateMarketplaceInstructionDataArgs,
+    any,
+    MigrateMarketplaceInstructionData
+  >(
+    struct<MigrateMarketplaceInstructionData>(
+      [['discriminator', bytes({ size: 8 })]],
+      { description: 'MigrateMarketplaceInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([166, 56, 109, 180, 190, 99, 237, 175]),
+    })
+  ) as Serializer<
+    MigrateMarketplaceInstructionDataArgs,
+    MigrateMarketplaceInstructionData
+  >;
+}
+
+// Instruction.
+export function migrateMarketplace(
+  context: Pick<Context, 'programs'>,
+  input: MigrateMarketplaceInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    admin: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    marketplace: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.marketplace ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigrateMarketplaceInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,139 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigrateUserAccountInstructionAccounts = {
+  user: Signer;
+  userAccount?: PublicKey | Pda;
+  marketplace: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MigrateUserAccountInstructionData = { discriminator: Uint8Array };
+
+export type MigrateUserAccountInstructionDataArgs = {};
+
+export function getMigrateUserAccountInstructionDataSerializer(): Serializer<
+  MigrateUserAccountInstructionDataArgs,
+  MigrateUserAccountInstructionData
+> {
+  return mapSerializer<
+    MigrateUserAccountInstructionDataArgs,
+    any,
+    MigrateUserAccountInstructionData
+  >(
+    struct<MigrateUserAccountInstructionData>(
+      [['discriminator', bytes({ size: 8 })]],
+      { description: 'MigrateUserAccountInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([198, 152, 90, 54, 134, 206, 92, 198]),
+    })
+  ) as Serializer<
+    MigrateUserAccountInstructionDataArgs,
+    MigrateUserAccountInstructionData
+  >;
+}
+
+// Instruction.
+export function migrateUserAccount(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: MigrateUserAccountInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    user: { index: 0, isWritable: true as boolean, value: input.user ?? null },
+    userAccount: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.userAccount ?? null,
+    },
+    marketplace: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.marketplace ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.userAccount.value) {
+    resolvedAccounts.userAccount.value = context.eddsa.findPda(programId, [
+      bytes().serialize(new Uint8Array([117, 115, 101, 114])),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.marketplace.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.user.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigrateUserAccountInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
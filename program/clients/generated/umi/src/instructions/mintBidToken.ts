@@ -35,7 +35,7 @@ import {
 
 // Accounts.
 export type MintBidTokenInstructionAccounts = {
-  admin: Signer;
+  admin?: Signer;
   user: Signer;
   userAccount?: PublicKey | Pda;
   marketplace: PublicKey | Pda;
@@ -43,7 +43,7 @@ export type MintBidTokenInstructionAccounts = {
   sbidMint: PublicKey | Pda;
   userSbidAta?: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
-  tokenProgram: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
   associatedTokenProgram?: PublicKey | Pda;
 };
 
@@ -167,17 +167,20 @@ export function mintBidToken(
       ),
     ]);
   }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'tokenProgram',
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
   if (!resolvedAccounts.userSbidAta.value) {
     resolvedAccounts.userSbidAta.value = context.eddsa.findPda(programId, [
       publicKeySerializer().serialize(
         expectPublicKey(resolvedAccounts.user.value)
       ),
-      bytes().serialize(
-        new Uint8Array([
-          6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-          121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126,
-          255, 0, 169,
-        ])
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.tokenProgram.value)
       ),
       publicKeySerializer().serialize(
         expectPublicKey(resolvedAccounts.sbidMint.value)
@@ -37,6 +37,9 @@ export type PlaceBidInstructionAccounts = {
   bidderSbidAta: PublicKey | Pda;
   mint: PublicKey | Pda;
   listing: PublicKey | Pda;
+  bidHistory?: PublicKey | Pda;
+  bidVault?: PublicKey | Pda;
+  previousBidder?: PublicKey | Pda;
   marketplace: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
   tokenProgram: PublicKey | Pda;
@@ -121,23 +124,38 @@ export function placeBid(
       isWritable: true as boolean,
       value: input.listing ?? null,
     },
-    marketplace: {
+    bidHistory: {
       index: 6,
       isWritable: true as boolean,
+      value: input.bidHistory ?? null,
+    },
+    bidVault: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.bidVault ?? null,
+    },
+    previousBidder: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.previousBidder ?? null,
+    },
+    marketplace: {
+      index: 9,
+      isWritable: true as boolean,
       value: input.marketplace ?? null,
     },
     systemProgram: {
-      index: 7,
+      index: 10,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     tokenProgram: {
-      index: 8,
+      index: 11,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     associatedTokenProgram: {
-      index: 9,
+      index: 12,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
@@ -158,6 +176,26 @@ export function placeBid(
       ),
     ]);
   }
+  if (!resolvedAccounts.bidHistory.value) {
+    resolvedAccounts.bidHistory.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 104, 105, 115, 116, 111, 114, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.bidVault.value) {
+    resolvedAccounts.bidVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 118, 97, 117, 108, 116])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+    ]);
+  }
   if (!resolvedAccounts.systemProgram.value) {
     resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
       'systemProgram',
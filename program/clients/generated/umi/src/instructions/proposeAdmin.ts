@@ -0,0 +1,121 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ProposeAdminInstructionAccounts = {
+  admin: Signer;
+  marketplace: PublicKey | Pda;
+};
+
+// Data.
+export type ProposeAdminInstructionData = {
+  discriminator: Uint8Array;
+  newAdmin: PublicKey;
+};
+
+export type ProposeAdminInstructionDataArgs = { newAdmin: PublicKey };
+
+export function getProposeAdminInstructionDataSerializer(): Serializer<
+  ProposeAdminInstructionDataArgs,
+  ProposeAdminInstructionData
+> {
+  return mapSerializer<
+    ProposeAdminInstructionDataArgs,
+    any,
+    ProposeAdminInstructionData
+  >(
+    struct<ProposeAdminInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['newAdmin', publicKeySerializer()],
+      ],
+      { description: 'ProposeAdminInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([121, 214, 199, 212, 87, 39, 117, 234]),
+    })
+  ) as Serializer<ProposeAdminInstructionDataArgs, ProposeAdminInstructionData>;
+}
+
+// Args.
+export type ProposeAdminInstructionArgs = ProposeAdminInstructionDataArgs;
+
+// Instruction.
+export function proposeAdmin(
+  context: Pick<Context, 'programs'>,
+  input: ProposeAdminInstructionAccounts & ProposeAdminInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    admin: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+    marketplace: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.marketplace ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ProposeAdminInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getProposeAdminInstructionDataSerializer().serialize(
+    resolvedArgs as ProposeAdminInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,339 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PurchaseInstructionAccounts = {
+  buyer: Signer;
+  seller: PublicKey | Pda;
+  buyerAta?: PublicKey | Pda;
+  mint: PublicKey | Pda;
+  collection: PublicKey | Pda;
+  listing: PublicKey | Pda;
+  bidHistory?: PublicKey | Pda;
+  bidVault?: PublicKey | Pda;
+  previousBidder?: PublicKey | Pda;
+  escrow?: PublicKey | Pda;
+  metadata?: PublicKey | Pda;
+  masterEdition?: PublicKey | Pda;
+  marketplace: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  metadataProgram?: PublicKey | Pda;
+  sysvarInstructions: PublicKey | Pda;
+};
+
+// Data.
+export type PurchaseInstructionData = {
+  discriminator: Uint8Array;
+  amount: bigint;
+};
+
+export type PurchaseInstructionDataArgs = { amount: number | bigint };
+
+export function getPurchaseInstructionDataSerializer(): Serializer<
+  PurchaseInstructionDataArgs,
+  PurchaseInstructionData
+> {
+  return mapSerializer<
+    PurchaseInstructionDataArgs,
+    any,
+    PurchaseInstructionData
+  >(
+    struct<PurchaseInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['amount', u64()],
+      ],
+      { description: 'PurchaseInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([21, 93, 113, 154, 193, 160, 242, 168]),
+    })
+  ) as Serializer<PurchaseInstructionDataArgs, PurchaseInstructionData>;
+}
+
+// Args.
+export type PurchaseInstructionArgs = PurchaseInstructionDataArgs;
+
+// Instruction.
+export function purchase(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: PurchaseInstructionAccounts & PurchaseInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    buyer: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.buyer ?? null,
+    },
+    seller: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.seller ?? null,
+    },
+    buyerAta: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.buyerAta ?? null,
+    },
+    mint: { index: 3, isWritable: false as boolean, value: input.mint ?? null },
+    collection: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    listing: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.listing ?? null,
+    },
+    bidHistory: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.bidHistory ?? null,
+    },
+    bidVault: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.bidVault ?? null,
+    },
+    previousBidder: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.previousBidder ?? null,
+    },
+    escrow: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.escrow ?? null,
+    },
+    metadata: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.metadata ?? null,
+    },
+    masterEdition: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.masterEdition ?? null,
+    },
+    marketplace: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.marketplace ?? null,
+    },
+    treasury: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    associatedTokenProgram: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 16,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    metadataProgram: {
+      index: 17,
+      isWritable: false as boolean,
+      value: input.metadataProgram ?? null,
+    },
+    sysvarInstructions: {
+      index: 18,
+      isWritable: false as boolean,
+      value: input.sysvarInstructions ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: PurchaseInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.buyerAta.value) {
+    resolvedAccounts.buyerAta.value = context.eddsa.findPda(programId, [
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.buyer.value)
+      ),
+      bytes().serialize(
+        new Uint8Array([
+          6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
+          121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126,
+          255, 0, 169,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.mint.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.bidHistory.value) {
+    resolvedAccounts.bidHistory.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 104, 105, 115, 116, 111, 114, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.bidVault.value) {
+    resolvedAccounts.bidVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([98, 105, 100, 95, 118, 97, 117, 108, 116])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.escrow.value) {
+    resolvedAccounts.escrow.value = context.eddsa.findPda(programId, [
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.listing.value)
+      ),
+      bytes().serialize(
+        new Uint8Array([
+          6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
+          121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126,
+          255, 0, 169,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.mint.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.metadataProgram.value) {
+    resolvedAccounts.metadataProgram.value = context.programs.getPublicKey(
+      'metadataProgram',
+      'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+    );
+    resolvedAccounts.metadataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.metadata.value) {
+    resolvedAccounts.metadata.value = context.eddsa.findPda(programId, [
+      bytes().serialize(new Uint8Array([109, 101, 116, 97, 100, 97, 116, 97])),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.metadataProgram.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.mint.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.masterEdition.value) {
+    resolvedAccounts.masterEdition.value = context.eddsa.findPda(programId, [
+      bytes().serialize(new Uint8Array([109, 101, 116, 97, 100, 97, 116, 97])),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.metadataProgram.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.mint.value)
+      ),
+      bytes().serialize(new Uint8Array([101, 100, 105, 116, 105, 111, 110])),
+    ]);
+  }
+  if (!resolvedAccounts.treasury.value) {
+    resolvedAccounts.treasury.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([116, 114, 101, 97, 115, 117, 114, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.marketplace.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value =
+      context.programs.getPublicKey(
+        'associatedTokenProgram',
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+      );
+    resolvedAccounts.associatedTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'tokenProgram',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPurchaseInstructionDataSerializer().serialize(
+    resolvedArgs as PurchaseInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
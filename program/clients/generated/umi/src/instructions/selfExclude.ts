@@ -0,0 +1,142 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  ExclusionPeriod,
+  ExclusionPeriodArgs,
+  getExclusionPeriodSerializer,
+} from '../types';
+
+// Accounts.
+export type SelfExcludeInstructionAccounts = {
+  user: Signer;
+  userAccount?: PublicKey | Pda;
+  marketplace: PublicKey | Pda;
+};
+
+// Data.
+export type SelfExcludeInstructionData = {
+  discriminator: Uint8Array;
+  period: ExclusionPeriod;
+};
+
+export type SelfExcludeInstructionDataArgs = { period: ExclusionPeriodArgs };
+
+export function getSelfExcludeInstructionDataSerializer(): Serializer<
+  SelfExcludeInstructionDataArgs,
+  SelfExcludeInstructionData
+> {
+  return mapSerializer<
+    SelfExcludeInstructionDataArgs,
+    any,
+    SelfExcludeInstructionData
+  >(
+    struct<SelfExcludeInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['period', getExclusionPeriodSerializer()],
+      ],
+      { description: 'SelfExcludeInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([18, 245, 91, 0, 7, 239, 172, 213]),
+    })
+  ) as Serializer<SelfExcludeInstructionDataArgs, SelfExcludeInstructionData>;
+}
+
+// Args.
+export type SelfExcludeInstructionArgs = SelfExcludeInstructionDataArgs;
+
+// Instruction.
+export function selfExclude(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: SelfExcludeInstructionAccounts & SelfExcludeInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    user: { index: 0, isWritable: false as boolean, value: input.user ?? null },
+    userAccount: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.userAccount ?? null,
+    },
+    marketplace: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.marketplace ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SelfExcludeInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.userAccount.value) {
+    resolvedAccounts.userAccount.value = context.eddsa.findPda(programId, [
+      bytes().serialize(new Uint8Array([117, 115, 101, 114])),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.marketplace.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.user.value)
+      ),
+    ]);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSelfExcludeInstructionDataSerializer().serialize(
+    resolvedArgs as SelfExcludeInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,136 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import { PauseFlags, PauseFlagsArgs, getPauseFlagsSerializer } from '../types';
+
+// Accounts.
+export type SetPauseInstructionAccounts = {
+  admin: Signer;
+  marketplace: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetPauseInstructionData = {
+  discriminator: Uint8Array;
+  paused: PauseFlags;
+};
+
+export type SetPauseInstructionDataArgs = { paused: PauseFlagsArgs };
+
+export function getSetPauseInstructionDataSerializer(): Serializer<
+  SetPauseInstructionDataArgs,
+  SetPauseInstructionData
+> {
+  return mapSerializer<
+    SetPauseInstructionDataArgs,
+    any,
+    SetPauseInstructionData
+  >(
+    struct<SetPauseInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['paused', getPauseFlagsSerializer()],
+      ],
+      { description: 'SetPauseInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([63, 32, 154, 2, 56, 103, 79, 45]),
+    })
+  ) as Serializer<SetPauseInstructionDataArgs, SetPauseInstructionData>;
+}
+
+// Args.
+export type SetPauseInstructionArgs = SetPauseInstructionDataArgs;
+
+// Instruction.
+export function setPause(
+  context: Pick<Context, 'programs'>,
+  input: SetPauseInstructionAccounts & SetPauseInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    admin: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    marketplace: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.marketplace ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetPauseInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetPauseInstructionDataSerializer().serialize(
+    resolvedArgs as SetPauseInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
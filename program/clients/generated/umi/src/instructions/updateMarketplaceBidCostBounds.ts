@@ -0,0 +1,149 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateMarketplaceBidCostBoundsInstructionAccounts = {
+  admin: Signer;
+  marketplace: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateMarketplaceBidCostBoundsInstructionData = {
+  discriminator: Uint8Array;
+  minBidCost: bigint;
+  maxBidCost: bigint;
+};
+
+export type UpdateMarketplaceBidCostBoundsInstructionDataArgs = {
+  minBidCost: number | bigint;
+  maxBidCost: number | bigint;
+};
+
+export function getUpdateMarketplaceBidCostBoundsInstructionDataSerializer(): Serializer<
+  UpdateMarketplaceBidCostBoundsInstructionDataArgs,
+  UpdateMarketplaceBidCostBoundsInstructionData
+> {
+  return mapSerializer<
+    UpdateMarketplaceBidCostBoundsInstructionDataArgs,
+    any,
+    UpdateMarketplaceBidCostBoundsInstructionData
+  >(
+    struct<UpdateMarketplaceBidCostBoundsInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['minBidCost', u64()],
+        ['maxBidCost', u64()],
+      ],
+      { description: 'UpdateMarketplaceBidCostBoundsInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([78, 200, 58, 164, 36, 224, 173, 4]),
+    })
+  ) as Serializer<
+    UpdateMarketplaceBidCostBoundsInstructionDataArgs,
+    UpdateMarketplaceBidCostBoundsInstructionData
+  >;
+}
+
+// Args.
+export type UpdateMarketplaceBidCostBoundsInstructionArgs =
+  UpdateMarketplaceBidCostBoundsInstructionDataArgs;
+
+// Instruction.
+export function updateMarketplaceBidCostBounds(
+  context: Pick<Context, 'programs'>,
+  input: UpdateMarketplaceBidCostBoundsInstructionAccounts &
+    UpdateMarketplaceBidCostBoundsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    admin: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    marketplace: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.marketplace ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateMarketplaceBidCostBoundsInstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getUpdateMarketplaceBidCostBoundsInstructionDataSerializer().serialize(
+      resolvedArgs as UpdateMarketplaceBidCostBoundsInstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,141 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+  u16,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateMarketplaceFeeInstructionAccounts = {
+  admin: Signer;
+  marketplace: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateMarketplaceFeeInstructionData = {
+  discriminator: Uint8Array;
+  fee: number;
+};
+
+export type UpdateMarketplaceFeeInstructionDataArgs = { fee: number };
+
+export function getUpdateMarketplaceFeeInstructionDataSerializer(): Serializer<
+  UpdateMarketplaceFeeInstructionDataArgs,
+  UpdateMarketplaceFeeInstructionData
+> {
+  return mapSerializer<
+    UpdateMarketplaceFeeInstructionDataArgs,
+    any,
+    UpdateMarketplaceFeeInstructionData
+  >(
+    struct<UpdateMarketplaceFeeInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['fee', u16()],
+      ],
+      { description: 'UpdateMarketplaceFeeInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([28, 15, 40, 156, 16, 211, 77, 17]),
+    })
+  ) as Serializer<
+    UpdateMarketplaceFeeInstructionDataArgs,
+    UpdateMarketplaceFeeInstructionData
+  >;
+}
+
+// Args.
+export type UpdateMarketplaceFeeInstructionArgs =
+  UpdateMarketplaceFeeInstructionDataArgs;
+
+// Instruction.
+export function updateMarketplaceFee(
+  context: Pick<Context, 'programs'>,
+  input: UpdateMarketplaceFeeInstructionAccounts &
+    UpdateMarketplaceFeeInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    admin: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    marketplace: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.marketplace ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateMarketplaceFeeInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateMarketplaceFeeInstructionDataSerializer().serialize(
+    resolvedArgs as UpdateMarketplaceFeeInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,144 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import { HappyHour, HappyHourArgs, getHappyHourSerializer } from '../types';
+
+// Accounts.
+export type UpdateMarketplaceHappyHourInstructionAccounts = {
+  admin: Signer;
+  marketplace: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateMarketplaceHappyHourInstructionData = {
+  discriminator: Uint8Array;
+  happyHour: HappyHour;
+};
+
+export type UpdateMarketplaceHappyHourInstructionDataArgs = {
+  happyHour: HappyHourArgs;
+};
+
+export function getUpdateMarketplaceHappyHourInstructionDataSerializer(): Serializer<
+  UpdateMarketplaceHappyHourInstructionDataArgs,
+  UpdateMarketplaceHappyHourInstructionData
+> {
+  return mapSerializer<
+    UpdateMarketplaceHappyHourInstructionDataArgs,
+    any,
+    UpdateMarketplaceHappyHourInstructionData
+  >(
+    struct<UpdateMarketplaceHappyHourInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['happyHour', getHappyHourSerializer()],
+      ],
+      { description: 'UpdateMarketplaceHappyHourInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([215, 189, 231, 16, 135, 148, 33, 118]),
+    })
+  ) as Serializer<
+    UpdateMarketplaceHappyHourInstructionDataArgs,
+    UpdateMarketplaceHappyHourInstructionData
+  >;
+}
+
+// Args.
+export type UpdateMarketplaceHappyHourInstructionArgs =
+  UpdateMarketplaceHappyHourInstructionDataArgs;
+
+// Instruction.
+export function updateMarketplaceHappyHour(
+  context: Pick<Context, 'programs'>,
+  input: UpdateMarketplaceHappyHourInstructionAccounts &
+    UpdateMarketplaceHappyHourInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    admin: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    marketplace: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.marketplace ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateMarketplaceHappyHourInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getUpdateMarketplaceHappyHourInstructionDataSerializer().serialize(
+      resolvedArgs as UpdateMarketplaceHappyHourInstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
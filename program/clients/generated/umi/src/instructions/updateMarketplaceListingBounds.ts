@@ -0,0 +1,150 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  ListingBounds,
+  ListingBoundsArgs,
+  getListingBoundsSerializer,
+} from '../types';
+
+// Accounts.
+export type UpdateMarketplaceListingBoundsInstructionAccounts = {
+  admin: Signer;
+  marketplace: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateMarketplaceListingBoundsInstructionData = {
+  discriminator: Uint8Array;
+  bounds: ListingBounds;
+};
+
+export type UpdateMarketplaceListingBoundsInstructionDataArgs = {
+  bounds: ListingBoundsArgs;
+};
+
+export function getUpdateMarketplaceListingBoundsInstructionDataSerializer(): Serializer<
+  UpdateMarketplaceListingBoundsInstructionDataArgs,
+  UpdateMarketplaceListingBoundsInstructionData
+> {
+  return mapSerializer<
+    UpdateMarketplaceListingBoundsInstructionDataArgs,
+    any,
+    UpdateMarketplaceListingBoundsInstructionData
+  >(
+    struct<UpdateMarketplaceListingBoundsInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['bounds', getListingBoundsSerializer()],
+      ],
+      { description: 'UpdateMarketplaceListingBoundsInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([124, 231, 100, 104, 172, 239, 77, 26]),
+    })
+  ) as Serializer<
+    UpdateMarketplaceListingBoundsInstructionDataArgs,
+    UpdateMarketplaceListingBoundsInstructionData
+  >;
+}
+
+// Args.
+export type UpdateMarketplaceListingBoundsInstructionArgs =
+  UpdateMarketplaceListingBoundsInstructionDataArgs;
+
+// Instruction.
+export function updateMarketplaceListingBounds(
+  context: Pick<Context, 'programs'>,
+  input: UpdateMarketplaceListingBoundsInstructionAccounts &
+    UpdateMarketplaceListingBoundsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    admin: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    marketplace: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.marketplace ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateMarketplaceListingBoundsInstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getUpdateMarketplaceListingBoundsInstructionDataSerializer().serialize(
+      resolvedArgs as UpdateMarketplaceListingBoundsInstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
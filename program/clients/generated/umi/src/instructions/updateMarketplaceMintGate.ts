@@ -0,0 +1,144 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  bytes,
+  mapSerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateMarketplaceMintGateInstructionAccounts = {
+  admin: Signer;
+  marketplace: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateMarketplaceMintGateInstructionData = {
+  discriminator: Uint8Array;
+  mintRequiresAdmin: boolean;
+};
+
+export type UpdateMarketplaceMintGateInstructionDataArgs = {
+  mintRequiresAdmin: boolean;
+};
+
+export function getUpdateMarketplaceMintGateInstructionDataSerializer(): Serializer<
+  UpdateMarketplaceMintGateInstructionDataArgs,
+  UpdateMarketplaceMintGateInstructionData
+> {
+  return mapSerializer<
+    UpdateMarketplaceMintGateInstructionDataArgs,
+    any,
+    UpdateMarketplaceMintGateInstructionData
+  >(
+    struct<UpdateMarketplaceMintGateInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['mintRequiresAdmin', bool()],
+      ],
+      { description: 'UpdateMarketplaceMintGateInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([238, 57, 169, 100, 2, 130, 78, 48]),
+    })
+  ) as Serializer<
+    UpdateMarketplaceMintGateInstructionDataArgs,
+    UpdateMarketplaceMintGateInstructionData
+  >;
+}
+
+// Args.
+export type UpdateMarketplaceMintGateInstructionArgs =
+  UpdateMarketplaceMintGateInstructionDataArgs;
+
+// Instruction.
+export function updateMarketplaceMintGate(
+  context: Pick<Context, 'programs'>,
+  input: UpdateMarketplaceMintGateInstructionAccounts &
+    UpdateMarketplaceMintGateInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    admin: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    marketplace: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.marketplace ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateMarketplaceMintGateInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getUpdateMarketplaceMintGateInstructionDataSerializer().serialize(
+      resolvedArgs as UpdateMarketplaceMintGateInstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
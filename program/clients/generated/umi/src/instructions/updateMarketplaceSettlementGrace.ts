@@ -0,0 +1,146 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateMarketplaceSettlementGraceInstructionAccounts = {
+  admin: Signer;
+  marketplace: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateMarketplaceSettlementGraceInstructionData = {
+  discriminator: Uint8Array;
+  graceInSlots: bigint;
+};
+
+export type UpdateMarketplaceSettlementGraceInstructionDataArgs = {
+  graceInSlots: number | bigint;
+};
+
+export function getUpdateMarketplaceSettlementGraceInstructionDataSerializer(): Serializer<
+  UpdateMarketplaceSettlementGraceInstructionDataArgs,
+  UpdateMarketplaceSettlementGraceInstructionData
+> {
+  return mapSerializer<
+    UpdateMarketplaceSettlementGraceInstructionDataArgs,
+    any,
+    UpdateMarketplaceSettlementGraceInstructionData
+  >(
+    struct<UpdateMarketplaceSettlementGraceInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['graceInSlots', u64()],
+      ],
+      { description: 'UpdateMarketplaceSettlementGraceInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([191, 106, 234, 94, 36, 173, 121, 106]),
+    })
+  ) as Serializer<
+    UpdateMarketplaceSettlementGraceInstructionDataArgs,
+    UpdateMarketplaceSettlementGraceInstructionData
+  >;
+}
+
+// Args.
+export type UpdateMarketplaceSettlementGraceInstructionArgs =
+  UpdateMarketplaceSettlementGraceInstructionDataArgs;
+
+// Instruction.
+export function updateMarketplaceSettlementGrace(
+  context: Pick<Context, 'programs'>,
+  input: UpdateMarketplaceSettlementGraceInstructionAccounts &
+    UpdateMarketplaceSettlementGraceInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    admin: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    marketplace: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.marketplace ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateMarketplaceSettlementGraceInstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getUpdateMarketplaceSettlementGraceInstructionDataSerializer().serialize(
+      resolvedArgs as UpdateMarketplaceSettlementGraceInstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,171 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateMarketplaceTokenMetadataInstructionAccounts = {
+  admin: Signer;
+  marketplace: PublicKey | Pda;
+  sbidMint: PublicKey | Pda;
+  tokenProgram2022?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateMarketplaceTokenMetadataInstructionData = {
+  discriminator: Uint8Array;
+  name: string;
+  symbol: string;
+  uri: string;
+};
+
+export type UpdateMarketplaceTokenMetadataInstructionDataArgs = {
+  name: string;
+  symbol: string;
+  uri: string;
+};
+
+export function getUpdateMarketplaceTokenMetadataInstructionDataSerializer(): Serializer<
+  UpdateMarketplaceTokenMetadataInstructionDataArgs,
+  UpdateMarketplaceTokenMetadataInstructionData
+> {
+  return mapSerializer<
+    UpdateMarketplaceTokenMetadataInstructionDataArgs,
+    any,
+    UpdateMarketplaceTokenMetadataInstructionData
+  >(
+    struct<UpdateMarketplaceTokenMetadataInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['name', string()],
+        ['symbol', string()],
+        ['uri', string()],
+      ],
+      { description: 'UpdateMarketplaceTokenMetadataInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([173, 144, 39, 147, 39, 135, 111, 49]),
+    })
+  ) as Serializer<
+    UpdateMarketplaceTokenMetadataInstructionDataArgs,
+    UpdateMarketplaceTokenMetadataInstructionData
+  >;
+}
+
+// Args.
+export type UpdateMarketplaceTokenMetadataInstructionArgs =
+  UpdateMarketplaceTokenMetadataInstructionDataArgs;
+
+// Instruction.
+export function updateMarketplaceTokenMetadata(
+  context: Pick<Context, 'programs'>,
+  input: UpdateMarketplaceTokenMetadataInstructionAccounts &
+    UpdateMarketplaceTokenMetadataInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    admin: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    marketplace: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.marketplace ?? null,
+    },
+    sbidMint: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.sbidMint ?? null,
+    },
+    tokenProgram2022: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.tokenProgram2022 ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateMarketplaceTokenMetadataInstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.tokenProgram2022.value) {
+    resolvedAccounts.tokenProgram2022.value = context.programs.getPublicKey(
+      'tokenProgram2022',
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
+    );
+    resolvedAccounts.tokenProgram2022.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getUpdateMarketplaceTokenMetadataInstructionDataSerializer().serialize(
+      resolvedArgs as UpdateMarketplaceTokenMetadataInstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
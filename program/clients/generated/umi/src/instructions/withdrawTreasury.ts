@@ -0,0 +1,164 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type WithdrawTreasuryInstructionAccounts = {
+  admin: Signer;
+  marketplace: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
+  destination: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type WithdrawTreasuryInstructionData = {
+  discriminator: Uint8Array;
+  amount: bigint;
+};
+
+export type WithdrawTreasuryInstructionDataArgs = { amount: number | bigint };
+
+export function getWithdrawTreasuryInstructionDataSerializer(): Serializer<
+  WithdrawTreasuryInstructionDataArgs,
+  WithdrawTreasuryInstructionData
+> {
+  return mapSerializer<
+    WithdrawTreasuryInstructionDataArgs,
+    any,
+    WithdrawTreasuryInstructionData
+  >(
+    struct<WithdrawTreasuryInstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['amount', u64()],
+      ],
+      { description: 'WithdrawTreasuryInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([40, 63, 122, 158, 144, 216, 83, 96]),
+    })
+  ) as Serializer<
+    WithdrawTreasuryInstructionDataArgs,
+    WithdrawTreasuryInstructionData
+  >;
+}
+
+// Args.
+export type WithdrawTreasuryInstructionArgs =
+  WithdrawTreasuryInstructionDataArgs;
+
+// Instruction.
+export function withdrawTreasury(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: WithdrawTreasuryInstructionAccounts & WithdrawTreasuryInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'nftMarketplace',
+    '8dsRGc9QXnsvqa5aCm21wS2M9xCPoVqxPpD3j6bysfyt'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    admin: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    marketplace: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.marketplace ?? null,
+    },
+    treasury: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    destination: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: WithdrawTreasuryInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treasury.value) {
+    resolvedAccounts.treasury.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([116, 114, 101, 97, 115, 117, 114, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.marketplace.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getWithdrawTreasuryInstructionDataSerializer().serialize(
+    resolvedArgs as WithdrawTreasuryInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
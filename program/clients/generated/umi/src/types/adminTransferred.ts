@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type AdminTransferred = {
+  marketplace: PublicKey;
+  previousAdmin: PublicKey;
+  newAdmin: PublicKey;
+  label: string;
+};
+
+export type AdminTransferredArgs = AdminTransferred;
+
+export function getAdminTransferredSerializer(): Serializer<
+  AdminTransferredArgs,
+  AdminTransferred
+> {
+  return struct<AdminTransferred>(
+    [
+      ['marketplace', publicKeySerializer()],
+      ['previousAdmin', publicKeySerializer()],
+      ['newAdmin', publicKeySerializer()],
+      ['label', string()],
+    ],
+    { description: 'AdminTransferred' }
+  ) as Serializer<AdminTransferredArgs, AdminTransferred>;
+}
@@ -0,0 +1,29 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+export type BidCostBounds = { minBidCost: bigint; maxBidCost: bigint };
+
+export type BidCostBoundsArgs = {
+  minBidCost: number | bigint;
+  maxBidCost: number | bigint;
+};
+
+export function getBidCostBoundsSerializer(): Serializer<
+  BidCostBoundsArgs,
+  BidCostBounds
+> {
+  return struct<BidCostBounds>(
+    [
+      ['minBidCost', u64()],
+      ['maxBidCost', u64()],
+    ],
+    { description: 'BidCostBounds' }
+  ) as Serializer<BidCostBoundsArgs, BidCostBounds>;
+}
@@ -0,0 +1,38 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Serializer,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import { LimitPeriod, LimitPeriodArgs, getLimitPeriodSerializer } from '.';
+
+export type BidLimits = {
+  period: LimitPeriod;
+  maxBids: number;
+  maxSpent: bigint;
+};
+
+export type BidLimitsArgs = {
+  period: LimitPeriodArgs;
+  maxBids: number;
+  maxSpent: number | bigint;
+};
+
+export function getBidLimitsSerializer(): Serializer<BidLimitsArgs, BidLimits> {
+  return struct<BidLimits>(
+    [
+      ['period', getLimitPeriodSerializer()],
+      ['maxBids', u32()],
+      ['maxSpent', u64()],
+    ],
+    { description: 'BidLimits' }
+  ) as Serializer<BidLimitsArgs, BidLimits>;
+}
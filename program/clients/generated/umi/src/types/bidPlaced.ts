@@ -9,6 +9,7 @@
 import { PublicKey } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  bool,
   publicKey as publicKeySerializer,
   string,
   struct,
@@ -20,6 +21,7 @@ export type BidPlaced = {
   listing: PublicKey;
   currentBid: bigint;
   endTimeInSlots: bigint;
+  happyHour: boolean;
   label: string;
 };
 
@@ -28,6 +30,7 @@ export type BidPlacedArgs = {
   listing: PublicKey;
   currentBid: number | bigint;
   endTimeInSlots: number | bigint;
+  happyHour: boolean;
   label: string;
 };
 
@@ -38,6 +41,7 @@ export function getBidPlacedSerializer(): Serializer<BidPlacedArgs, BidPlaced> {
       ['listing', publicKeySerializer()],
       ['currentBid', u64()],
       ['endTimeInSlots', u64()],
+      ['happyHour', bool()],
       ['label', string()],
     ],
     { description: 'BidPlaced' }
@@ -0,0 +1,42 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type BidRecord = {
+  bidder: PublicKey;
+  bid: bigint;
+  slot: bigint;
+  timestamp: bigint;
+};
+
+export type BidRecordArgs = {
+  bidder: PublicKey;
+  bid: number | bigint;
+  slot: number | bigint;
+  timestamp: number | bigint;
+};
+
+export function getBidRecordSerializer(): Serializer<BidRecordArgs, BidRecord> {
+  return struct<BidRecord>(
+    [
+      ['bidder', publicKeySerializer()],
+      ['bid', u64()],
+      ['slot', u64()],
+      ['timestamp', i64()],
+    ],
+    { description: 'BidRecord' }
+  ) as Serializer<BidRecordArgs, BidRecord>;
+}
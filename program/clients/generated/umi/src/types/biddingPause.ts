@@ -0,0 +1,36 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, struct } from '@metaplex-foundation/umi/serializers';
+import { PauseTime, PauseTimeArgs, getPauseTimeSerializer } from '.';
+
+export type BiddingPause = {
+  startedAt: PauseTime;
+  lastDuration: PauseTime;
+  totalDuration: PauseTime;
+};
+
+export type BiddingPauseArgs = {
+  startedAt: PauseTimeArgs;
+  lastDuration: PauseTimeArgs;
+  totalDuration: PauseTimeArgs;
+};
+
+export function getBiddingPauseSerializer(): Serializer<
+  BiddingPauseArgs,
+  BiddingPause
+> {
+  return struct<BiddingPause>(
+    [
+      ['startedAt', getPauseTimeSerializer()],
+      ['lastDuration', getPauseTimeSerializer()],
+      ['totalDuration', getPauseTimeSerializer()],
+    ],
+    { description: 'BiddingPause' }
+  ) as Serializer<BiddingPauseArgs, BiddingPause>;
+}
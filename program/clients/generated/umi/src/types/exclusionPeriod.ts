@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum ExclusionPeriod {
+  Day,
+  Week,
+  Month,
+}
+
+export type ExclusionPeriodArgs = ExclusionPeriod;
+
+export function getExclusionPeriodSerializer(): Serializer<
+  ExclusionPeriodArgs,
+  ExclusionPeriod
+> {
+  return scalarEnum<ExclusionPeriod>(ExclusionPeriod, {
+    description: 'ExclusionPeriod',
+  }) as Serializer<ExclusionPeriodArgs, ExclusionPeriod>;
+}
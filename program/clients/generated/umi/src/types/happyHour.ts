@@ -0,0 +1,42 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+  u16,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  HappyHourWindow,
+  HappyHourWindowArgs,
+  getHappyHourWindowSerializer,
+} from '.';
+
+export type HappyHour = {
+  windows: Array<HappyHourWindow>;
+  incrementMultiplierBps: number;
+  extensionMultiplierBps: number;
+};
+
+export type HappyHourArgs = {
+  windows: Array<HappyHourWindowArgs>;
+  incrementMultiplierBps: number;
+  extensionMultiplierBps: number;
+};
+
+export function getHappyHourSerializer(): Serializer<HappyHourArgs, HappyHour> {
+  return struct<HappyHour>(
+    [
+      ['windows', array(getHappyHourWindowSerializer(), { size: 3 })],
+      ['incrementMultiplierBps', u16()],
+      ['extensionMultiplierBps', u16()],
+    ],
+    { description: 'HappyHour' }
+  ) as Serializer<HappyHourArgs, HappyHour>;
+}
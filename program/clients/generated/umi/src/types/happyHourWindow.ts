@@ -0,0 +1,29 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, struct, u32 } from '@metaplex-foundation/umi/serializers';
+
+export type HappyHourWindow = {
+  startSecondOfDay: number;
+  endSecondOfDay: number;
+};
+
+export type HappyHourWindowArgs = HappyHourWindow;
+
+export function getHappyHourWindowSerializer(): Serializer<
+  HappyHourWindowArgs,
+  HappyHourWindow
+> {
+  return struct<HappyHourWindow>(
+    [
+      ['startSecondOfDay', u32()],
+      ['endSecondOfDay', u32()],
+    ],
+    { description: 'HappyHourWindow' }
+  ) as Serializer<HappyHourWindowArgs, HappyHourWindow>;
+}
@@ -6,11 +6,35 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './adminTransferred';
+export * from './bidCostBounds';
+export * from './bidLimits';
 export * from './bidPlaced';
+export * from './bidRecord';
+export * from './biddingPause';
+export * from './exclusionPeriod';
+export * from './happyHour';
+export * from './happyHourWindow';
+export * from './limitPeriod';
+export * from './listParams';
+export * from './listingBounds';
 export * from './listingCreated';
 export * from './listingDelisted';
+export * from './listingEndOutcome';
 export * from './listingEnded';
+export * from './listingPurchased';
+export * from './listingSettled';
 export * from './marketplaceInitialized';
+export * from './marketplacePaused';
+export * from './marketplaceUpdate';
+export * from './marketplaceUpdated';
 export * from './mintCostTier';
 export * from './mintTier';
+export * from './pauseFlags';
+export * from './pauseTime';
+export * from './sbidMetadata';
+export * from './timerMode';
+export * from './timingMode';
+export * from './treasuryWithdrawn';
 export * from './userCreated';
+export * from './userSelfExcluded';
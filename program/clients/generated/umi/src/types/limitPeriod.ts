@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum LimitPeriod {
+  Daily,
+  Weekly,
+}
+
+export type LimitPeriodArgs = LimitPeriod;
+
+export function getLimitPeriodSerializer(): Serializer<
+  LimitPeriodArgs,
+  LimitPeriod
+> {
+  return scalarEnum<LimitPeriod>(LimitPeriod, {
+    description: 'LimitPeriod',
+  }) as Serializer<LimitPeriodArgs, LimitPeriod>;
+}
@@ -0,0 +1,72 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Serializer,
+  bool,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  TimerMode,
+  TimerModeArgs,
+  TimingMode,
+  TimingModeArgs,
+  getTimerModeSerializer,
+  getTimingModeSerializer,
+} from '.';
+
+export type ListParams = {
+  bidCost: bigint;
+  bidIncrement: bigint;
+  timerExtensionInSlots: bigint;
+  startTimeInSlots: bigint;
+  initialDurationInSlots: bigint;
+  timerMode: TimerMode;
+  timingMode: TimingMode;
+  maxEndTimeInSlots: bigint;
+  buyoutPrice: bigint;
+  reservePrice: bigint;
+  solEscrow: boolean;
+};
+
+export type ListParamsArgs = {
+  bidCost: number | bigint;
+  bidIncrement: number | bigint;
+  timerExtensionInSlots: number | bigint;
+  startTimeInSlots: number | bigint;
+  initialDurationInSlots: number | bigint;
+  timerMode: TimerModeArgs;
+  timingMode: TimingModeArgs;
+  maxEndTimeInSlots: number | bigint;
+  buyoutPrice: number | bigint;
+  reservePrice: number | bigint;
+  solEscrow: boolean;
+};
+
+export function getListParamsSerializer(): Serializer<
+  ListParamsArgs,
+  ListParams
+> {
+  return struct<ListParams>(
+    [
+      ['bidCost', u64()],
+      ['bidIncrement', u64()],
+      ['timerExtensionInSlots', u64()],
+      ['startTimeInSlots', u64()],
+      ['initialDurationInSlots', u64()],
+      ['timerMode', getTimerModeSerializer()],
+      ['timingMode', getTimingModeSerializer()],
+      ['maxEndTimeInSlots', u64()],
+      ['buyoutPrice', u64()],
+      ['reservePrice', u64()],
+      ['solEscrow', bool()],
+    ],
+    { description: 'ListParams' }
+  ) as Serializer<ListParamsArgs, ListParams>;
+}
@@ -0,0 +1,41 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+export type ListingBounds = {
+  minBidIncrement: bigint;
+  maxBidIncrement: bigint;
+  minTimerExtensionInSlots: bigint;
+  maxTimerExtensionInSlots: bigint;
+  maxDurationInSlots: bigint;
+};
+
+export type ListingBoundsArgs = {
+  minBidIncrement: number | bigint;
+  maxBidIncrement: number | bigint;
+  minTimerExtensionInSlots: number | bigint;
+  maxTimerExtensionInSlots: number | bigint;
+  maxDurationInSlots: number | bigint;
+};
+
+export function getListingBoundsSerializer(): Serializer<
+  ListingBoundsArgs,
+  ListingBounds
+> {
+  return struct<ListingBounds>(
+    [
+      ['minBidIncrement', u64()],
+      ['maxBidIncrement', u64()],
+      ['minTimerExtensionInSlots', u64()],
+      ['maxTimerExtensionInSlots', u64()],
+      ['maxDurationInSlots', u64()],
+    ],
+    { description: 'ListingBounds' }
+  ) as Serializer<ListingBoundsArgs, ListingBounds>;
+}
@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum ListingEndOutcome {
+  Sold,
+  ReserveNotMet,
+}
+
+export type ListingEndOutcomeArgs = ListingEndOutcome;
+
+export function getListingEndOutcomeSerializer(): Serializer<
+  ListingEndOutcomeArgs,
+  ListingEndOutcome
+> {
+  return scalarEnum<ListingEndOutcome>(ListingEndOutcome, {
+    description: 'ListingEndOutcome',
+  }) as Serializer<ListingEndOutcomeArgs, ListingEndOutcome>;
+}
@@ -12,11 +12,37 @@ import {
   publicKey as publicKeySerializer,
   string,
   struct,
+  u64,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  ListingEndOutcome,
+  ListingEndOutcomeArgs,
+  getListingEndOutcomeSerializer,
+} from '.';
 
-export type ListingEnded = { listingPubkey: PublicKey; label: string };
+export type ListingEnded = {
+  listingPubkey: PublicKey;
+  mint: PublicKey;
+  seller: PublicKey;
+  winner: PublicKey;
+  winningBid: bigint;
+  amountToTreasury: bigint;
+  amountToSeller: bigint;
+  outcome: ListingEndOutcome;
+  label: string;
+};
 
-export type ListingEndedArgs = ListingEnded;
+export type ListingEndedArgs = {
+  listingPubkey: PublicKey;
+  mint: PublicKey;
+  seller: PublicKey;
+  winner: PublicKey;
+  winningBid: number | bigint;
+  amountToTreasury: number | bigint;
+  amountToSeller: number | bigint;
+  outcome: ListingEndOutcomeArgs;
+  label: string;
+};
 
 export function getListingEndedSerializer(): Serializer<
   ListingEndedArgs,
@@ -25,6 +51,13 @@ export function getListingEndedSerializer(): Serializer<
   return struct<ListingEnded>(
     [
       ['listingPubkey', publicKeySerializer()],
+      ['mint', publicKeySerializer()],
+      ['seller', publicKeySerializer()],
+      ['winner', publicKeySerializer()],
+      ['winningBid', u64()],
+      ['amountToTreasury', u64()],
+      ['amountToSeller', u64()],
+      ['outcome', getListingEndOutcomeSerializer()],
       ['label', string()],
     ],
     { description: 'ListingEnded' }
@@ -0,0 +1,51 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type ListingPurchased = {
+  listingPubkey: PublicKey;
+  buyer: PublicKey;
+  buyoutPrice: bigint;
+  amountToTreasury: bigint;
+  amountToSeller: bigint;
+  label: string;
+};
+
+export type ListingPurchasedArgs = {
+  listingPubkey: PublicKey;
+  buyer: PublicKey;
+  buyoutPrice: number | bigint;
+  amountToTreasury: number | bigint;
+  amountToSeller: number | bigint;
+  label: string;
+};
+
+export function getListingPurchasedSerializer(): Serializer<
+  ListingPurchasedArgs,
+  ListingPurchased
+> {
+  return struct<ListingPurchased>(
+    [
+      ['listingPubkey', publicKeySerializer()],
+      ['buyer', publicKeySerializer()],
+      ['buyoutPrice', u64()],
+      ['amountToTreasury', u64()],
+      ['amountToSeller', u64()],
+      ['label', string()],
+    ],
+    { description: 'ListingPurchased' }
+  ) as Serializer<ListingPurchasedArgs, ListingPurchased>;
+}
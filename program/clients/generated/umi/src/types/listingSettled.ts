@@ -0,0 +1,51 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type ListingSettled = {
+  listingPubkey: PublicKey;
+  mint: PublicKey;
+  seller: PublicKey;
+  unpaidWinner: PublicKey;
+  unpaidBid: bigint;
+  label: string;
+};
+
+export type ListingSettledArgs = {
+  listingPubkey: PublicKey;
+  mint: PublicKey;
+  seller: PublicKey;
+  unpaidWinner: PublicKey;
+  unpaidBid: number | bigint;
+  label: string;
+};
+
+export function getListingSettledSerializer(): Serializer<
+  ListingSettledArgs,
+  ListingSettled
+> {
+  return struct<ListingSettled>(
+    [
+      ['listingPubkey', publicKeySerializer()],
+      ['mint', publicKeySerializer()],
+      ['seller', publicKeySerializer()],
+      ['unpaidWinner', publicKeySerializer()],
+      ['unpaidBid', u64()],
+      ['label', string()],
+    ],
+    { description: 'ListingSettled' }
+  ) as Serializer<ListingSettledArgs, ListingSettled>;
+}
@@ -0,0 +1,42 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import { PauseFlags, PauseFlagsArgs, getPauseFlagsSerializer } from '.';
+
+export type MarketplacePaused = {
+  marketplace: PublicKey;
+  paused: PauseFlags;
+  label: string;
+};
+
+export type MarketplacePausedArgs = {
+  marketplace: PublicKey;
+  paused: PauseFlagsArgs;
+  label: string;
+};
+
+export function getMarketplacePausedSerializer(): Serializer<
+  MarketplacePausedArgs,
+  MarketplacePaused
+> {
+  return struct<MarketplacePaused>(
+    [
+      ['marketplace', publicKeySerializer()],
+      ['paused', getPauseFlagsSerializer()],
+      ['label', string()],
+    ],
+    { description: 'MarketplacePaused' }
+  ) as Serializer<MarketplacePausedArgs, MarketplacePaused>;
+}
@@ -0,0 +1,110 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  struct,
+  u16,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  BidCostBounds,
+  BidCostBoundsArgs,
+  ListingBounds,
+  ListingBoundsArgs,
+  SbidMetadata,
+  SbidMetadataArgs,
+  getBidCostBoundsSerializer,
+  getListingBoundsSerializer,
+  getSbidMetadataSerializer,
+} from '.';
+
+export type MarketplaceUpdate =
+  | { __kind: 'Fee'; old: number; new: number }
+  | { __kind: 'TokenMetadata'; old: SbidMetadata; new: SbidMetadata }
+  | { __kind: 'ListingBounds'; old: ListingBounds; new: ListingBounds }
+  | { __kind: 'BidCostBounds'; old: BidCostBounds; new: BidCostBounds };
+
+export type MarketplaceUpdateArgs =
+  | { __kind: 'Fee'; old: number; new: number }
+  | { __kind: 'TokenMetadata'; old: SbidMetadataArgs; new: SbidMetadataArgs }
+  | { __kind: 'ListingBounds'; old: ListingBoundsArgs; new: ListingBoundsArgs }
+  | { __kind: 'BidCostBounds'; old: BidCostBoundsArgs; new: BidCostBoundsArgs };
+
+export function getMarketplaceUpdateSerializer(): Serializer<
+  MarketplaceUpdateArgs,
+  MarketplaceUpdate
+> {
+  return dataEnum<MarketplaceUpdate>(
+    [
+      [
+        'Fee',
+        struct<GetDataEnumKindContent<MarketplaceUpdate, 'Fee'>>([
+          ['old', u16()],
+          ['new', u16()],
+        ]),
+      ],
+      [
+        'TokenMetadata',
+        struct<GetDataEnumKindContent<MarketplaceUpdate, 'TokenMetadata'>>([
+          ['old', getSbidMetadataSerializer()],
+          ['new', getSbidMetadataSerializer()],
+        ]),
+      ],
+      [
+        'ListingBounds',
+        struct<GetDataEnumKindContent<MarketplaceUpdate, 'ListingBounds'>>([
+          ['old', getListingBoundsSerializer()],
+          ['new', getListingBoundsSerializer()],
+        ]),
+      ],
+      [
+        'BidCostBounds',
+        struct<GetDataEnumKindContent<MarketplaceUpdate, 'BidCostBounds'>>([
+          ['old', getBidCostBoundsSerializer()],
+          ['new', getBidCostBoundsSerializer()],
+        ]),
+      ],
+    ],
+    { description: 'MarketplaceUpdate' }
+  ) as Serializer<MarketplaceUpdateArgs, MarketplaceUpdate>;
+}
+
+// Data Enum Helpers.
+export function marketplaceUpdate(
+  kind: 'Fee',
+  data: GetDataEnumKindContent<MarketplaceUpdateArgs, 'Fee'>
+): GetDataEnumKind<MarketplaceUpdateArgs, 'Fee'>;
+export function marketplaceUpdate(
+  kind: 'TokenMetadata',
+  data: GetDataEnumKindContent<MarketplaceUpdateArgs, 'TokenMetadata'>
+): GetDataEnumKind<MarketplaceUpdateArgs, 'TokenMetadata'>;
+export function marketplaceUpdate(
+  kind: 'ListingBounds',
+  data: GetDataEnumKindContent<MarketplaceUpdateArgs, 'ListingBounds'>
+): GetDataEnumKind<MarketplaceUpdateArgs, 'ListingBounds'>;
+export function marketplaceUpdate(
+  kind: 'BidCostBounds',
+  data: GetDataEnumKindContent<MarketplaceUpdateArgs, 'BidCostBounds'>
+): GetDataEnumKind<MarketplaceUpdateArgs, 'BidCostBounds'>;
+export function marketplaceUpdate<K extends MarketplaceUpdateArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<MarketplaceUpdateArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isMarketplaceUpdate<K extends MarketplaceUpdate['__kind']>(
+  kind: K,
+  value: MarketplaceUpdate
+): value is MarketplaceUpdate & { __kind: K } {
+  return value.__kind === kind;
+}
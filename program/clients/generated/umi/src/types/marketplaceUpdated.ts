@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  MarketplaceUpdate,
+  MarketplaceUpdateArgs,
+  getMarketplaceUpdateSerializer,
+} from '.';
+
+export type MarketplaceUpdated = {
+  marketplace: PublicKey;
+  update: MarketplaceUpdate;
+  label: string;
+};
+
+export type MarketplaceUpdatedArgs = {
+  marketplace: PublicKey;
+  update: MarketplaceUpdateArgs;
+  label: string;
+};
+
+export function getMarketplaceUpdatedSerializer(): Serializer<
+  MarketplaceUpdatedArgs,
+  MarketplaceUpdated
+> {
+  return struct<MarketplaceUpdated>(
+    [
+      ['marketplace', publicKeySerializer()],
+      ['update', getMarketplaceUpdateSerializer()],
+      ['label', string()],
+    ],
+    { description: 'MarketplaceUpdated' }
+  ) as Serializer<MarketplaceUpdatedArgs, MarketplaceUpdated>;
+}
@@ -0,0 +1,33 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, bool, struct } from '@metaplex-foundation/umi/serializers';
+
+export type PauseFlags = {
+  listing: boolean;
+  bidding: boolean;
+  minting: boolean;
+  settlement: boolean;
+};
+
+export type PauseFlagsArgs = PauseFlags;
+
+export function getPauseFlagsSerializer(): Serializer<
+  PauseFlagsArgs,
+  PauseFlags
+> {
+  return struct<PauseFlags>(
+    [
+      ['listing', bool()],
+      ['bidding', bool()],
+      ['minting', bool()],
+      ['settlement', bool()],
+    ],
+    { description: 'PauseFlags' }
+  ) as Serializer<PauseFlagsArgs, PauseFlags>;
+}
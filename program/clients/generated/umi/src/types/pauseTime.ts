@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+export type PauseTime = { slots: bigint; seconds: bigint };
+
+export type PauseTimeArgs = {
+  slots: number | bigint;
+  seconds: number | bigint;
+};
+
+export function getPauseTimeSerializer(): Serializer<PauseTimeArgs, PauseTime> {
+  return struct<PauseTime>(
+    [
+      ['slots', u64()],
+      ['seconds', u64()],
+    ],
+    { description: 'PauseTime' }
+  ) as Serializer<PauseTimeArgs, PauseTime>;
+}
@@ -0,0 +1,31 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Serializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type SbidMetadata = { name: string; symbol: string; uri: string };
+
+export type SbidMetadataArgs = SbidMetadata;
+
+export function getSbidMetadataSerializer(): Serializer<
+  SbidMetadataArgs,
+  SbidMetadata
+> {
+  return struct<SbidMetadata>(
+    [
+      ['name', string()],
+      ['symbol', string()],
+      ['uri', string()],
+    ],
+    { description: 'SbidMetadata' }
+  ) as Serializer<SbidMetadataArgs, SbidMetadata>;
+}
@@ -0,0 +1,23 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum TimerMode {
+  Cumulative,
+  ResetToAtLeast,
+  Capped,
+}
+
+export type TimerModeArgs = TimerMode;
+
+export function getTimerModeSerializer(): Serializer<TimerModeArgs, TimerMode> {
+  return scalarEnum<TimerMode>(TimerMode, {
+    description: 'TimerMode',
+  }) as Serializer<TimerModeArgs, TimerMode>;
+}
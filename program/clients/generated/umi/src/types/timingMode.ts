@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum TimingMode {
+  Slots,
+  UnixTimestamp,
+}
+
+export type TimingModeArgs = TimingMode;
+
+export function getTimingModeSerializer(): Serializer<
+  TimingModeArgs,
+  TimingMode
+> {
+  return scalarEnum<TimingMode>(TimingMode, {
+    description: 'TimingMode',
+  }) as Serializer<TimingModeArgs, TimingMode>;
+}
@@ -0,0 +1,45 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TreasuryWithdrawn = {
+  marketplace: PublicKey;
+  destination: PublicKey;
+  amount: bigint;
+  label: string;
+};
+
+export type TreasuryWithdrawnArgs = {
+  marketplace: PublicKey;
+  destination: PublicKey;
+  amount: number | bigint;
+  label: string;
+};
+
+export function getTreasuryWithdrawnSerializer(): Serializer<
+  TreasuryWithdrawnArgs,
+  TreasuryWithdrawn
+> {
+  return struct<TreasuryWithdrawn>(
+    [
+      ['marketplace', publicKeySerializer()],
+      ['destination', publicKeySerializer()],
+      ['amount', u64()],
+      ['label', string()],
+    ],
+    { description: 'TreasuryWithdrawn' }
+  ) as Serializer<TreasuryWithdrawnArgs, TreasuryWithdrawn>;
+}
@@ -0,0 +1,45 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type UserSelfExcluded = {
+  userPubkey: PublicKey;
+  excludedFrom: bigint;
+  excludedUntil: bigint;
+  label: string;
+};
+
+export type UserSelfExcludedArgs = {
+  userPubkey: PublicKey;
+  excludedFrom: number | bigint;
+  excludedUntil: number | bigint;
+  label: string;
+};
+
+export function getUserSelfExcludedSerializer(): Serializer<
+  UserSelfExcludedArgs,
+  UserSelfExcluded
+> {
+  return struct<UserSelfExcluded>(
+    [
+      ['userPubkey', publicKeySerializer()],
+      ['excludedFrom', i64()],
+      ['excludedUntil', i64()],
+      ['label', string()],
+    ],
+    { description: 'UserSelfExcluded' }
+  ) as Serializer<UserSelfExcludedArgs, UserSelfExcluded>;
+}
@@ -1,12 +1,55 @@
 pub const MS_IN_SEC: i64 = 1000;
+/// Target slot time, used to convert slot durations into seconds
+pub const MS_PER_SLOT: u64 = 400;
 
 pub const MARKET_INITIALIZED_LABEL: &str = "market_initialized";
 pub const LISTING_CREATED_LABEL: &str = "listing_created";
 pub const LISTING_ENDED_LABEL: &str = "listing_ended";
+pub const LISTING_PURCHASED_LABEL: &str = "listing_purchased";
 pub const LISTING_DELISTED_LABEL: &str = "listing_delisted";
 pub const BID_PLACED_LABEL: &str = "bid_placed";
 pub const USER_CREATED_LABEL: &str = "user_created";
+pub const TREASURY_WITHDRAWN_LABEL: &str = "treasury_withdrawn";
+pub const USER_SELF_EXCLUDED_LABEL: &str = "user_self_excluded";
+pub const LISTING_SETTLED_LABEL: &str = "listing_settled";
+pub const ADMIN_TRANSFERRED_LABEL: &str = "admin_transferred";
+pub const MARKETPLACE_PAUSED_LABEL: &str = "marketplace_paused";
+pub const MARKETPLACE_UPDATED_LABEL: &str = "marketplace_updated";
+
+pub const SECONDS_IN_DAY: i64 = 24 * 60 * 60;
+pub const SECONDS_IN_WEEK: i64 = 7 * SECONDS_IN_DAY;
+pub const SECONDS_IN_MONTH: i64 = 30 * SECONDS_IN_DAY;
 
 pub const REWARD_TIER_1: u32 = 1;
 pub const REWARD_TIER_2: u32 = 10;
 pub const REWARD_TIER_3: u32 = 50;
+
+pub const PENALTY_UNPAID_AUCTION: u32 = 100;
+
+pub const BID_HISTORY_LENGTH: usize = 10;
+pub const HAPPY_HOUR_WINDOWS: usize = 3;
+
+pub const BPS_DENOMINATOR: u64 = 10000;
+// 10%
+pub const MAX_MARKETPLACE_FEE_BPS: u16 = 1000;
+// 50% of the tier amount
+pub const MAX_MINT_TIER_BONUS_BPS: u16 = 5000;
+
+// ~1 day with 400ms slots
+pub const DEFAULT_SETTLEMENT_GRACE_IN_SLOTS: u64 = 216_000;
+
+pub const SBID_DECIMALS: u8 = 6;
+// Listings before version 1 stored bid_cost in whole sBid instead of base units
+pub const LISTING_VERSION: u8 = 1;
+
+// 0.1 and 5 sBid with 6 decimals
+pub const DEFAULT_MIN_BID_COST: u64 = 100_000;
+pub const DEFAULT_MAX_BID_COST: u64 = 5_000_000;
+
+// 1 lamport to 10 sol per bid
+pub const DEFAULT_MIN_BID_INCREMENT: u64 = 1;
+pub const DEFAULT_MAX_BID_INCREMENT: u64 = 10_000_000_000;
+// up to ~10 minutes per bid and ~7 days per auction with 400ms slots
+pub const DEFAULT_MIN_TIMER_EXTENSION_IN_SLOTS: u64 = 0;
+pub const DEFAULT_MAX_TIMER_EXTENSION_IN_SLOTS: u64 = 1_500;
+pub const DEFAULT_MAX_DURATION_IN_SLOTS: u64 = 1_512_000;
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::ADMIN_TRANSFERRED_LABEL, errors::MarketplaceErrorCode, events::AdminTransferred,
+    state::Marketplace,
+};
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        address = marketplace.pending_admin @ MarketplaceErrorCode::InvalidPendingAdmin
+    )]
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump
+    )]
+    marketplace: Account<'info, Marketplace>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn accept_admin(&mut self) -> Result<()> {
+        let previous_admin = self.marketplace.admin;
+
+        self.marketplace.admin = self.pending_admin.key();
+        self.marketplace.pending_admin = Pubkey::default();
+
+        emit!(AdminTransferred {
+            marketplace: self.marketplace.key(),
+            previous_admin,
+            new_admin: self.marketplace.admin,
+            label: ADMIN_TRANSFERRED_LABEL.to_string()
+        });
+
+        Ok(())
+    }
+}
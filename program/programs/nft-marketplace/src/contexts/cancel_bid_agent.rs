@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface};
+
+use crate::state::{BidAgent, Marketplace};
+
+#[derive(Accounts)]
+pub struct CancelBidAgent<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner,
+        has_one = marketplace,
+        seeds = [b"bid_agent", bid_agent.listing.key().as_ref(), owner.key().as_ref()],
+        bump = bid_agent.bump
+    )]
+    pub bid_agent: Box<Account<'info, BidAgent>>,
+
+    #[account(
+        has_one = sbid_mint,
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump
+    )]
+    marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(mut)]
+    pub sbid_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = sbid_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_sbid_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelBidAgent<'info> {
+    pub fn cancel_bid_agent(&mut self) -> Result<()> {
+        let amount = self.bid_agent.deposited;
+
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let bump = [self.marketplace.bump];
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"marketplace",
+            self.marketplace.creator.as_ref(),
+            self.marketplace.sbid_mint.as_ref(),
+            self.marketplace.name.as_bytes(),
+            &bump,
+        ][..]];
+
+        // Return the unspent deposit to the owner
+        let accounts = MintTo {
+            mint: self.sbid_mint.to_account_info(),
+            to: self.owner_sbid_ata.to_account_info(),
+            authority: self.marketplace.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            accounts,
+            &signer_seeds,
+        );
+
+        mint_to(cpi_context, amount)
+    }
+}
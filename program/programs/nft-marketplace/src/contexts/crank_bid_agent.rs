@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::REWARD_TIER_1,
+    state::{BidAgent, BidHistory, ListingV2, Marketplace, UserAccount},
+    utils::{
+        apply_bid, assert_already_highest_bidder, assert_auction_active,
+        assert_bid_history_provided, assert_not_self_excluded, credit_paused_time, track_bid_limit,
+        MarketplaceErrorCode,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CrankBidAgent<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = listing,
+        has_one = marketplace,
+        seeds = [b"bid_agent", listing.key().as_ref(), bid_agent.owner.key().as_ref()],
+        bump = bid_agent.bump
+    )]
+    pub bid_agent: Box<Account<'info, BidAgent>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", marketplace.key().as_ref(), bid_agent.owner.key().as_ref()],
+        bump = user_account.bump
+    )]
+    pub user_account: Box<Account<'info, UserAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"listing", marketplace.key().as_ref(), listing.mint.key().as_ref(), listing.seed.to_le_bytes().as_ref()],
+        bump = listing.bump
+    )]
+    listing: Box<Account<'info, ListingV2>>,
+
+    /// Missing on listings created before bid histories
+    #[account(
+        mut,
+        has_one = listing,
+        seeds = [b"bid_history", listing.key().as_ref()],
+        bump = bid_history.bump
+    )]
+    bid_history: Option<Box<Account<'info, BidHistory>>>,
+
+    #[account(
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump
+    )]
+    marketplace: Box<Account<'info, Marketplace>>,
+}
+
+impl<'info> CrankBidAgent<'info> {
+    pub fn crank_bid_agent(&mut self) -> Result<()> {
+        let owner = self.bid_agent.owner;
+
+        require!(
+            !self.marketplace.paused.bidding,
+            MarketplaceErrorCode::BiddingPaused
+        );
+        assert_not_self_excluded(&self.user_account)?;
+        credit_paused_time(&mut self.listing, &self.marketplace.bidding_pause)?;
+        assert_auction_active(&self.listing)?;
+        assert_bid_history_provided(&self.listing, &self.bid_history)?;
+        assert_already_highest_bidder(&self.listing, &owner)?;
+
+        require!(
+            self.bid_agent.bids_placed < self.bid_agent.max_bids,
+            MarketplaceErrorCode::BidAgentMaxBidsReached
+        );
+
+        if self.bid_agent.bid_window_in_slots > 0 {
+            let now = self.listing.current_time(&Clock::get()?);
+            let time_left = self.listing.end_time_in_slots.saturating_sub(now);
+
+            require!(
+                time_left
+                    <= self
+                        .listing
+                        .duration_from_slots(self.bid_agent.bid_window_in_slots)?,
+                MarketplaceErrorCode::BidAgentOutsideBidWindow
+            );
+        }
+
+        track_bid_limit(&mut self.user_account)?;
+
+        // Spend from the deposit instead of burning, it was burned on deposit
+        self.bid_agent.deposited = self
+            .bid_agent
+            .deposited
+            .checked_sub(self.listing.bid_cost_amount()?)
+            .ok_or(MarketplaceErrorCode::BidAgentInsufficientDeposit)?;
+        self.bid_agent.bids_placed = self
+            .bid_agent
+            .bids_placed
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        apply_bid(
+            &mut self.listing,
+            self.bid_history.as_mut(),
+            &self.marketplace.happy_hour,
+            &owner,
+        )?;
+
+        require!(
+            self.listing.current_bid <= self.bid_agent.max_price,
+            MarketplaceErrorCode::BidAgentMaxPriceReached
+        );
+
+        Ok(())
+    }
+
+    pub fn reward_user(&mut self) -> Result<()> {
+        self.user_account.points = self
+            .user_account
+            .points
+            .checked_add(REWARD_TIER_1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.user_account.total_bids_placed = self
+            .user_account
+            .total_bids_placed
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+}
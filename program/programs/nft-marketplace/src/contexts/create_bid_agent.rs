@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    state::{BidAgent, ListingV2, Marketplace, UserAccount},
+    utils::{assert_auction_active, assert_not_self_excluded, MarketplaceErrorCode},
+};
+
+#[derive(Accounts)]
+pub struct CreateBidAgent<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"user", marketplace.key().as_ref(), owner.key().as_ref()],
+        bump = user_account.bump
+    )]
+    pub user_account: Box<Account<'info, UserAccount>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + BidAgent::INIT_SPACE,
+        seeds = [b"bid_agent", listing.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub bid_agent: Box<Account<'info, BidAgent>>,
+
+    #[account(
+        seeds = [b"listing", marketplace.key().as_ref(), listing.mint.key().as_ref(), listing.seed.to_le_bytes().as_ref()],
+        bump = listing.bump
+    )]
+    listing: Box<Account<'info, ListingV2>>,
+
+    #[account(
+        has_one = sbid_mint,
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump
+    )]
+    marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(mut)]
+    pub sbid_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = sbid_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_sbid_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CreateBidAgent<'info> {
+    pub fn create_bid_agent(
+        &mut self,
+        max_price: u64,
+        max_bids: u32,
+        bid_window_in_slots: u64,
+        deposit: u64,
+        bumps: &CreateBidAgentBumps,
+    ) -> Result<()> {
+        assert_not_self_excluded(&self.user_account)?;
+        assert_auction_active(&self.listing)?;
+
+        // Agents only spend sBid, they can't lock SOL for their bids
+        require!(
+            !self.listing.sol_escrow,
+            MarketplaceErrorCode::SolEscrowNotSupported
+        );
+        require!(
+            max_price > self.listing.current_bid && max_bids > 0 && deposit > 0,
+            MarketplaceErrorCode::InvalidBidAgent
+        );
+
+        self.bid_agent.set_inner(BidAgent {
+            owner: self.owner.key(),
+            listing: self.listing.key(),
+            marketplace: self.marketplace.key(),
+            max_price,
+            max_bids,
+            bids_placed: 0,
+            bid_window_in_slots,
+            deposited: deposit,
+            bump: bumps.bid_agent,
+
+            padding: [0; 7],
+            _reserved: [0; 32],
+        });
+
+        // sBid is non-transferable, so the deposit is burned here and
+        // minted back to the owner when the agent is cancelled
+        let accounts = Burn {
+            mint: self.sbid_mint.to_account_info(),
+            from: self.owner_sbid_ata.to_account_info(),
+            authority: self.owner.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new(self.token_program.to_account_info(), accounts);
+
+        burn(cpi_context, deposit)
+    }
+}
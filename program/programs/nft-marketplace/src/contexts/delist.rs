@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::{MasterEditionAccount, Metadata, MetadataAccount},
+    token::Token,
+    token_interface::{close_account, CloseAccount, Mint, TokenAccount},
+};
+
+use crate::{
+    constants::LISTING_DELISTED_LABEL,
+    events::ListingDelisted,
+    state::{BidHistory, ListingV2, Marketplace},
+    transfer::transfer_asset,
+    utils::{
+        assert_auction_delist_eligible, assert_bid_history_provided, close_bid_vault,
+        MarketplaceErrorCode,
+    },
+};
+
+#[derive(Accounts)]
+pub struct Delist<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+    )]
+    seller_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        address = listing.mint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        close = seller,
+        has_one = seller,
+        has_one = mint,
+        seeds = [b"listing", marketplace.key().as_ref(), listing.mint.key().as_ref(), listing.seed.to_le_bytes().as_ref()],
+        bump = listing.bump
+    )]
+    listing: Box<Account<'info, ListingV2>>,
+
+    /// Missing on listings created before bid histories
+    #[account(
+        mut,
+        close = seller,
+        has_one = listing,
+        seeds = [b"bid_history", listing.key().as_ref()],
+        bump = bid_history.bump
+    )]
+    bid_history: Option<Box<Account<'info, BidHistory>>>,
+
+    /// Only used by `sol_escrow` listings
+    #[account(
+        mut,
+        seeds = [b"bid_vault", listing.key().as_ref()],
+        bump = listing.bid_vault_bump
+    )]
+    bid_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+    )]
+    escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+
+    #[account(
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref(), b"edition"],
+        seeds::program = metadata_program.key(),
+        bump
+    )]
+    pub master_edition: Box<Account<'info, MasterEditionAccount>>,
+
+    #[account(
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump
+    )]
+    marketplace: Box<Account<'info, Marketplace>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub metadata_program: Program<'info, Metadata>,
+    /// CHECK: The sysvar instructions account. This account is checked in metadata transfer
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+impl<'info> Delist<'info> {
+    pub fn delist<'a>(
+        &mut self,
+        amount: u64,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            self.listing.is_active,
+            MarketplaceErrorCode::AuctionNotActive
+        );
+        assert_auction_delist_eligible(&self.listing)?;
+        assert_bid_history_provided(&self.listing, &self.bid_history)?;
+
+        // Return the NFT to the seller, the listing account is closed by anchor
+        self.withdraw_and_close(amount, remaining_accounts)?;
+
+        self.listing.is_active = false;
+
+        emit!(ListingDelisted {
+            listing_pubkey: self.listing.key(),
+            label: LISTING_DELISTED_LABEL.to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_and_close<'a>(
+        &mut self,
+        amount: u64,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<()> {
+        let bump = [self.listing.bump];
+        let seed = self.listing.seed.to_le_bytes();
+        let signer_seeds = [&[
+            b"listing",
+            self.marketplace.to_account_info().key.as_ref(),
+            self.mint.to_account_info().key.as_ref(),
+            seed.as_ref(),
+            &bump,
+        ][..]];
+
+        transfer_asset(
+            amount,
+            &self.escrow.to_account_info(),
+            &self.seller_ata.to_account_info(),
+            &self.listing.to_account_info(),
+            &self.seller.to_account_info(),
+            &self.seller.to_account_info(),
+            &self.mint,
+            &self.metadata.to_account_info(),
+            &self.token_program,
+            &self.system_program,
+            &self.associated_token_program,
+            &self.sysvar_instructions,
+            remaining_accounts,
+            Some(signer_seeds),
+        )?;
+
+        // Close the escrow account
+        let accounts = CloseAccount {
+            account: self.escrow.to_account_info(),
+            destination: self.seller.to_account_info(),
+            authority: self.listing.to_account_info(),
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            accounts,
+            &signer_seeds,
+        );
+
+        close_account(ctx)?;
+
+        // Return the rent funded into the bid vault on list
+        close_bid_vault(
+            &self.listing,
+            &self.bid_vault,
+            self.seller.to_account_info(),
+            self.system_program.to_account_info(),
+        )
+    }
+}
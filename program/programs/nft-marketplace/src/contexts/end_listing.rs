@@ -9,9 +9,14 @@ use anchor_spl::{
 use crate::{
     constants::{LISTING_ENDED_LABEL, REWARD_TIER_3},
     events::ListingEnded,
-    state::{ListingV2, Marketplace, UserAccount},
+    state::{BidHistory, ListingEndOutcome, ListingV2, Marketplace, UserAccount},
     transfer::transfer_asset,
-    utils::{assert_allowed_claimer, assert_auction_ended, transfer_sol, MarketplaceErrorCode},
+    utils::{
+        assert_allowed_claimer, assert_auction_ended, assert_bid_history_provided,
+        assert_not_awaiting_pause_credit, assert_within_settlement_grace, close_bid_vault,
+        credit_paused_time, escrow_bid_vault, is_reserve_met, split_marketplace_fee, transfer_sol,
+        transfer_sol_from_bid_vault, MarketplaceErrorCode,
+    },
 };
 
 #[derive(Accounts)]
@@ -19,11 +24,6 @@ pub struct EndListing<'info> {
     #[account(mut)]
     user: Signer<'info>,
 
-    #[account(
-        address = marketplace.admin @ MarketplaceErrorCode::InvalidListingAuthority
-    )]
-    admin: Signer<'info>,
-
     #[account(
         mut,
         seeds = [b"user", marketplace.key().as_ref(), user.key().as_ref()],
@@ -55,12 +55,38 @@ pub struct EndListing<'info> {
 
     #[account(
         mut,
+        close = seller,
         has_one = mint,
         seeds = [b"listing", marketplace.key().as_ref(), listing.mint.key().as_ref(), listing.seed.to_le_bytes().as_ref()],
         bump = listing.bump
     )]
     listing: Box<Account<'info, ListingV2>>,
 
+    /// Missing on listings created before bid histories
+    #[account(
+        mut,
+        close = seller,
+        has_one = listing,
+        seeds = [b"bid_history", listing.key().as_ref()],
+        bump = bid_history.bump
+    )]
+    bid_history: Option<Box<Account<'info, BidHistory>>>,
+
+    /// Only used by `sol_escrow` listings
+    #[account(
+        mut,
+        seeds = [b"bid_vault", listing.key().as_ref()],
+        bump = listing.bid_vault_bump
+    )]
+    bid_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        address = listing.highest_bidder @ MarketplaceErrorCode::InvalidPreviousBidder
+    )]
+    /// CHECK: This is the highest bidder - "address" constraint will take care of that.
+    highest_bidder: Option<UncheckedAccount<'info>>,
+
     #[account(
         mut,
         associated_token::mint = mint,
@@ -87,7 +113,7 @@ pub struct EndListing<'info> {
 
     #[account(
         mut,
-        seeds = [b"marketplace", marketplace.admin.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_str().as_bytes()],
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
         bump = marketplace.bump
     )]
     marketplace: Box<Account<'info, Marketplace>>,
@@ -116,24 +142,120 @@ impl<'info> EndListing<'info> {
         //let auction = self.listing;
 
         require!(self.listing.is_active, MarketplaceErrorCode::AuctionEnded);
+        require!(
+            !self.marketplace.paused.settlement,
+            MarketplaceErrorCode::SettlementPaused
+        );
+        assert_bid_history_provided(&self.listing, &self.bid_history)?;
+        credit_paused_time(&mut self.listing, &self.marketplace.bidding_pause)?;
+        assert_not_awaiting_pause_credit(&self.listing, &self.marketplace)?;
         assert_auction_ended(&self.listing)?;
+        assert_within_settlement_grace(&self.listing, self.marketplace.settlement_grace_in_slots)?;
+
+        if !is_reserve_met(&self.listing) {
+            return self.end_below_reserve(amount, remaining_accounts);
+        }
+
         assert_allowed_claimer(&self.listing, &self.user.to_account_info())?;
 
-        // Transfer the current_bid price to treasury
-        transfer_sol(
-            self.user.to_account_info(),
-            self.treasury.to_account_info(),
-            self.system_program.to_account_info(),
-            self.listing.current_bid,
-        )?;
+        let (amount_to_treasury, amount_to_seller) =
+            split_marketplace_fee(self.listing.current_bid, self.marketplace.fee)?;
+
+        if self.listing.sol_escrow {
+            // The winning bid is already locked in the bid vault
+            transfer_sol_from_bid_vault(
+                &self.listing,
+                escrow_bid_vault(&self.bid_vault)?,
+                self.treasury.to_account_info(),
+                self.system_program.to_account_info(),
+                amount_to_treasury,
+            )?;
+            transfer_sol_from_bid_vault(
+                &self.listing,
+                escrow_bid_vault(&self.bid_vault)?,
+                self.seller.to_account_info(),
+                self.system_program.to_account_info(),
+                amount_to_seller,
+            )?;
+        } else {
+            // Transfer the marketplace fee to treasury
+            transfer_sol(
+                self.user.to_account_info(),
+                self.treasury.to_account_info(),
+                self.system_program.to_account_info(),
+                amount_to_treasury,
+            )?;
+
+            // Transfer the remaining amount to the seller
+            transfer_sol(
+                self.user.to_account_info(),
+                self.seller.to_account_info(),
+                self.system_program.to_account_info(),
+                amount_to_seller,
+            )?;
+        }
+
+        // Transfer the NFT to the user, the listing account is closed by anchor
+        self.withdraw_and_close(amount, remaining_accounts)?;
+
+        self.listing.is_active = false;
+
+        emit!(ListingEnded {
+            listing_pubkey: self.listing.key(),
+            mint: self.listing.mint,
+            seller: self.listing.seller,
+            winner: self.user.key(),
+            winning_bid: self.listing.current_bid,
+            amount_to_treasury,
+            amount_to_seller,
+            outcome: ListingEndOutcome::Sold,
+            label: LISTING_ENDED_LABEL.to_string(),
+        });
+
+        Ok(())
+    }
 
-        // Transfer the NFT to the user
+    /// The reserve price wasn't met, the seller gets the NFT back
+    fn end_below_reserve<'a>(
+        &mut self,
+        amount: u64,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            self.user.key() == self.listing.seller,
+            MarketplaceErrorCode::ClaimerIsNotSeller
+        );
+
+        // Refund the escrowed highest bid
+        if self.listing.sol_escrow && self.listing.highest_bidder != Pubkey::default() {
+            let highest_bidder = self
+                .highest_bidder
+                .as_ref()
+                .ok_or(MarketplaceErrorCode::InvalidPreviousBidder)?;
+
+            transfer_sol_from_bid_vault(
+                &self.listing,
+                escrow_bid_vault(&self.bid_vault)?,
+                highest_bidder.to_account_info(),
+                self.system_program.to_account_info(),
+                self.listing.current_bid,
+            )?;
+        }
+
+        // Transfer the NFT back to the seller, the listing account is closed by anchor
         self.withdraw_and_close(amount, remaining_accounts)?;
 
         self.listing.is_active = false;
 
         emit!(ListingEnded {
             listing_pubkey: self.listing.key(),
+            mint: self.listing.mint,
+            seller: self.listing.seller,
+            winner: Pubkey::default(),
+            winning_bid: self.listing.current_bid,
+            amount_to_treasury: 0,
+            amount_to_seller: 0,
+            outcome: ListingEndOutcome::ReserveNotMet,
             label: LISTING_ENDED_LABEL.to_string(),
         });
 
@@ -161,6 +283,7 @@ impl<'info> EndListing<'info> {
             &self.user_ata.to_account_info(),
             &self.listing.to_account_info(),
             &self.user.to_account_info(),
+            &self.user.to_account_info(),
             &self.mint,
             &self.metadata.to_account_info(),
             &self.token_program,
@@ -184,10 +307,23 @@ impl<'info> EndListing<'info> {
             &signer_seeds,
         );
 
-        close_account(ctx)
+        close_account(ctx)?;
+
+        // Return the rent funded into the bid vault on list
+        close_bid_vault(
+            &self.listing,
+            &self.bid_vault,
+            self.seller.to_account_info(),
+            self.system_program.to_account_info(),
+        )
     }
 
     pub fn reward_user(&mut self) -> Result<()> {
+        // Nobody wins an auction that ended below its reserve price
+        if !is_reserve_met(&self.listing) {
+            return Ok(());
+        }
+
         self.user_account.points = self
             .user_account
             .points
@@ -19,10 +19,14 @@ use anchor_spl::{
 use solana_program::program::invoke_signed;
 
 use crate::{
-    constants::MARKET_INITIALIZED_LABEL,
+    constants::{
+        DEFAULT_MAX_BID_COST, DEFAULT_MIN_BID_COST, DEFAULT_SETTLEMENT_GRACE_IN_SLOTS,
+        MARKET_INITIALIZED_LABEL, MAX_MARKETPLACE_FEE_BPS, SBID_DECIMALS,
+    },
     errors::MarketplaceErrorCode,
     events::MarketplaceInitialized,
-    state::{Marketplace, MintTier},
+    state::{BiddingPause, HappyHour, ListingBounds, Marketplace, MintTier, PauseFlags},
+    utils::assert_valid_mint_tiers,
 };
 
 #[derive(Accounts)]
@@ -38,7 +42,7 @@ pub struct Initialize<'info> {
         init,
         payer = admin,
         space = 8 + Marketplace::INIT_SPACE,
-        seeds = [b"marketplace", admin.key().as_ref(), sbid_mint.key().as_ref(), name.as_str().as_bytes()],
+        seeds = [b"marketplace", admin.key().as_ref(), sbid_mint.key().as_ref(), name.as_bytes()],
         bump,
     )]
     marketplace: Account<'info, Marketplace>,
@@ -65,6 +69,11 @@ impl<'info> Initialize<'info> {
             name.len() > 0 && name.len() < 33,
             MarketplaceErrorCode::MarketplaceNameTooLong
         );
+        require!(
+            fee <= MAX_MARKETPLACE_FEE_BPS,
+            MarketplaceErrorCode::InvalidMarketplaceFee
+        );
+        assert_valid_mint_tiers(&mint_tiers)?;
 
         let inner = Marketplace {
             admin: self.admin.key(),
@@ -75,9 +84,19 @@ impl<'info> Initialize<'info> {
             mint_tiers,
             bump: bumps.marketplace,
             treasury_bump: bumps.treasury,
-
-            padding: [0; 2],
-            _reserved: [0; 64],
+            mint_requires_admin: false,
+
+            padding: [0; 1],
+            happy_hour: HappyHour::default(),
+            settlement_grace_in_slots: DEFAULT_SETTLEMENT_GRACE_IN_SLOTS,
+            min_bid_cost: DEFAULT_MIN_BID_COST,
+            max_bid_cost: DEFAULT_MAX_BID_COST,
+            listing_bounds: ListingBounds::default(),
+            creator: self.admin.key(),
+            pending_admin: Pubkey::default(),
+            paused: PauseFlags::default(),
+            bidding_pause: BiddingPause::default(),
+            _reserved: [0; 12],
         };
 
         self.marketplace.set_inner(inner.clone());
@@ -101,7 +120,7 @@ impl<'info> Initialize<'info> {
             b"marketplace",
             self.admin.to_account_info().key.as_ref(),
             self.sbid_mint.to_account_info().key.as_ref(),
-            self.marketplace.name.as_str().as_bytes(),
+            self.marketplace.name.as_bytes(),
             &bump,
         ][..]];
 
@@ -166,7 +185,7 @@ impl<'info> Initialize<'info> {
                     mint: self.sbid_mint.to_account_info(),
                 },
             ),
-            6,                             // decimals
+            SBID_DECIMALS,                 // decimals
             &self.marketplace.key(),       // mint authority
             Some(&self.marketplace.key()), // freeze authority
         )?;
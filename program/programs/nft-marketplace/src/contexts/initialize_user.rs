@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use crate::{
     constants::USER_CREATED_LABEL,
     events::UserCreated,
-    state::{Marketplace, UserAccount},
+    state::{BidLimits, LimitPeriod, Marketplace, UserAccount},
 };
 
 #[derive(Accounts)]
@@ -22,7 +22,7 @@ pub struct InitializeUser<'info> {
     pub user_account: Account<'info, UserAccount>,
 
     #[account(
-        seeds = [b"marketplace", marketplace.admin.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_str().as_bytes()],
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
         bump = marketplace.bump,
     )]
     marketplace: Account<'info, Marketplace>,
@@ -41,8 +41,20 @@ impl<'info> InitializeUser<'info> {
             points: 0,
             bump: bumps.user_account,
 
-            padding: [0; 3],
-            _reserved: [0; 32],
+            bid_limits: BidLimits {
+                period: LimitPeriod::Daily,
+                max_bids: 0,
+                max_spent: 0,
+            },
+            limit_window_start: 0,
+            bids_in_window: 0,
+            spent_in_window: 0,
+            pending_bid_limits: None,
+
+            excluded_until: 0,
+            total_auctions_unpaid: 0,
+
+            _reserved: [0; 2],
         });
 
         emit!(UserCreated {
@@ -12,11 +12,17 @@ use anchor_spl::{
 };
 
 use crate::{
-    constants::{LISTING_CREATED_LABEL, REWARD_TIER_2},
+    constants::{BID_HISTORY_LENGTH, LISTING_CREATED_LABEL, LISTING_VERSION, REWARD_TIER_2},
     events::ListingCreated,
-    state::{ListingV2, Marketplace, UserAccount},
+    state::{
+        BidHistory, BidRecord, ListParams, ListingV2, Marketplace, TimerMode, TimingMode,
+        UserAccount,
+    },
     transfer::transfer_asset,
-    utils::MarketplaceErrorCode,
+    utils::{
+        assert_not_self_excluded, assert_valid_bid_cost, assert_valid_listing_params, transfer_sol,
+        MarketplaceErrorCode,
+    },
 };
 
 #[derive(Accounts)]
@@ -47,9 +53,25 @@ pub struct List<'info> {
     )]
     listing: Box<Account<'info, ListingV2>>,
 
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + BidHistory::INIT_SPACE,
+        seeds = [b"bid_history", listing.key().as_ref()],
+        bump
+    )]
+    bid_history: Box<Account<'info, BidHistory>>,
+
     #[account(
         mut,
-        seeds = [b"marketplace", admin.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_str().as_bytes()],
+        seeds = [b"bid_vault", listing.key().as_ref()],
+        bump
+    )]
+    bid_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
         bump = marketplace.bump
     )]
     marketplace: Box<Account<'info, Marketplace>>,
@@ -100,21 +122,56 @@ impl<'info> List<'info> {
     pub fn create_listing(
         &mut self,
         seed: u64,
-        bid_increment: u64,
-        timer_extension_in_slots: u64,
-        start_time_in_slots: u64,
-        initial_duration_in_slots: u64,
-        buyout_price: u64,
+        params: ListParams,
         bumps: &ListBumps,
     ) -> Result<()> {
+        let ListParams {
+            bid_cost,
+            bid_increment,
+            timer_extension_in_slots,
+            start_time_in_slots,
+            initial_duration_in_slots,
+            timer_mode,
+            timing_mode,
+            max_end_time_in_slots,
+            buyout_price,
+            reserve_price,
+            sol_escrow,
+        } = params;
+
+        require!(
+            !self.marketplace.paused.listing,
+            MarketplaceErrorCode::ListingPaused
+        );
+        assert_not_self_excluded(&self.user_account)?;
+        assert_valid_bid_cost(&self.marketplace, bid_cost)?;
+        assert_valid_listing_params(
+            &self.marketplace,
+            timing_mode,
+            bid_increment,
+            timer_extension_in_slots,
+            start_time_in_slots,
+            initial_duration_in_slots,
+            buyout_price,
+        )?;
+        require!(
+            buyout_price == 0 || reserve_price <= buyout_price,
+            MarketplaceErrorCode::InvalidReservePrice
+        );
+
         let end_time_in_slots = start_time_in_slots
             .checked_add(initial_duration_in_slots)
             .ok_or(ProgramError::ArithmeticOverflow)?;
 
+        require!(
+            timer_mode != TimerMode::Capped || max_end_time_in_slots >= end_time_in_slots,
+            MarketplaceErrorCode::InvalidTimerMode
+        );
+
         self.listing.set_inner(ListingV2 {
             mint: self.mint.key(),
             seller: self.seller.key(),
-            bid_cost: 1,
+            bid_cost,
             bid_increment,
             current_bid: 0,
             highest_bidder: Pubkey::default(),
@@ -125,14 +182,64 @@ impl<'info> List<'info> {
             buyout_price,
             seed,
             bump: bumps.listing,
+            sol_escrow,
+            bid_vault_bump: bumps.bid_vault,
+            timer_mode,
+            timing_mode,
+            version: LISTING_VERSION,
 
-            padding: [0; 6],
-            _reserved: [0; 32],
+            padding: [0; 1],
+            reserve_price,
+            max_end_time_in_slots,
+            pause_credit_snapshot: self.get_pause_credit_snapshot(timing_mode)?,
+            _reserved: [0; 8],
         });
 
+        self.bid_history.set_inner(BidHistory {
+            listing: self.listing.key(),
+            head: 0,
+            count: 0,
+            bump: bumps.bid_history,
+
+            padding: [0; 5],
+            bids: [BidRecord::default(); BID_HISTORY_LENGTH],
+        });
+
+        // Fund the bid vault rent so escrowed bids of any size keep it rent exempt,
+        // the seller gets it back when the listing is closed
+        if sol_escrow {
+            let rent = Rent::get()?
+                .minimum_balance(0)
+                .saturating_sub(self.bid_vault.lamports());
+
+            if rent > 0 {
+                transfer_sol(
+                    self.seller.to_account_info(),
+                    self.bid_vault.to_account_info(),
+                    self.system_program.to_account_info(),
+                    rent,
+                )?;
+            }
+        }
+
         Ok(())
     }
 
+    /// A listing created during a bidding pause is only credited the rest of it
+    fn get_pause_credit_snapshot(&self, timing_mode: TimingMode) -> Result<u64> {
+        let pause = &self.marketplace.bidding_pause;
+        let mut snapshot = pause.total_duration.get(timing_mode);
+
+        if self.marketplace.paused.bidding {
+            let now = timing_mode.current_time(&Clock::get()?);
+            snapshot = snapshot
+                .checked_add(now.saturating_sub(pause.started_at.get(timing_mode)))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+
+        Ok(snapshot)
+    }
+
     pub fn transfer_to_escrow2<'a>(
         &mut self,
         amount: u64,
@@ -144,6 +251,7 @@ impl<'info> List<'info> {
             &self.escrow.to_account_info(),
             &self.seller.to_account_info(),
             &self.listing.to_account_info(),
+            &self.listing.to_account_info(),
             &self.mint,
             &self.metadata.to_account_info(),
             &self.token_program,
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::DEFAULT_SETTLEMENT_GRACE_IN_SLOTS,
+    state::{ListingBounds, Marketplace},
+    utils::realloc_legacy_account,
+};
+
+#[derive(Accounts)]
+pub struct MigrateMarketplace<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: may still have the layout it was created with, which `Account`
+    /// can't deserialize, the discriminator and admin are checked on migration
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub marketplace: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateMarketplace<'info> {
+    /// Grows a marketplace created before the settlement grace, the listing bounds,
+    /// the admin handover and the pause flags and fills in their defaults, zeroed
+    /// pause fields leave it unpaused. It is a no-op on an up to date marketplace.
+    pub fn migrate_marketplace(&mut self) -> Result<()> {
+        let marketplace_info = self.marketplace.to_account_info();
+
+        realloc_legacy_account::<Marketplace>(
+            &marketplace_info,
+            8 + Marketplace::INIT_SPACE,
+            &self.admin.to_account_info(),
+            &self.system_program.to_account_info(),
+        )?;
+
+        let mut marketplace =
+            Marketplace::try_deserialize(&mut &marketplace_info.try_borrow_data()?[..])?;
+
+        require_keys_eq!(
+            marketplace.admin,
+            self.admin.key(),
+            ErrorCode::ConstraintHasOne
+        );
+
+        // Legacy marketplaces were derived from their admin, which could not change,
+        // and a zeroed grace would leave settle_listing as the only way to end an auction
+        if marketplace.creator == Pubkey::default() {
+            marketplace.creator = marketplace.admin;
+            marketplace.settlement_grace_in_slots = DEFAULT_SETTLEMENT_GRACE_IN_SLOTS;
+        }
+
+        // Zeroed bounds reject every listing
+        if marketplace.listing_bounds.max_bid_increment == 0 {
+            marketplace.listing_bounds = ListingBounds::default();
+        }
+
+        marketplace.try_serialize(&mut &mut marketplace_info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Marketplace, UserAccount},
+    utils::realloc_legacy_account,
+};
+
+#[derive(Accounts)]
+pub struct MigrateUserAccount<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: may still have the layout it was created with, which `Account`
+    /// can't deserialize, the discriminator is checked before the realloc
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"user", marketplace.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_account: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump,
+    )]
+    marketplace: Account<'info, Marketplace>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateUserAccount<'info> {
+    /// Grows a user account created before the bid limits, self exclusion and
+    /// unpaid auction fields, it is a no-op on an up to date account.
+    pub fn migrate_user_account(&mut self) -> Result<()> {
+        realloc_legacy_account::<UserAccount>(
+            &self.user_account.to_account_info(),
+            8 + UserAccount::INIT_SPACE,
+            &self.user.to_account_info(),
+            &self.system_program.to_account_info(),
+        )
+    }
+}
@@ -4,13 +4,16 @@ use anchor_lang::{
 };
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
+    token_interface::{mint_to, Mint, MintTo, Token2022, TokenAccount},
 };
 
 use crate::{
     constants::REWARD_TIER_1,
+    errors::MarketplaceErrorCode,
     state::{Marketplace, MintCostTier, UserAccount},
-    utils::{assert_valid_mint_tier_costs, get_mint_tier},
+    utils::{
+        assert_not_self_excluded, assert_valid_mint_tier_costs, get_mint_tier, track_spend_limit,
+    },
 };
 
 #[derive(Accounts)]
@@ -18,7 +21,7 @@ pub struct MintBidToken<'info> {
     #[account(
         address = marketplace.admin
     )]
-    admin: Signer<'info>,
+    admin: Option<Signer<'info>>,
 
     #[account(mut)]
     pub user: Signer<'info>,
@@ -34,7 +37,7 @@ pub struct MintBidToken<'info> {
     #[account(
         has_one = sbid_mint,
         has_one = treasury,
-        seeds = [b"marketplace", admin.key().as_ref(), sbid_mint.key().as_ref(), marketplace.name.as_str().as_bytes()],
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
         bump = marketplace.bump
     )]
     marketplace: Box<Account<'info, Marketplace>>,
@@ -50,23 +53,35 @@ pub struct MintBidToken<'info> {
     pub sbid_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = user,
         associated_token::mint = sbid_mint,
         associated_token::authority = user,
+        associated_token::token_program = token_program,
     )]
     pub user_sbid_ata: InterfaceAccount<'info, TokenAccount>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Interface<'info, TokenInterface>,
+    pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl<'info> MintBidToken<'info> {
     pub fn mint_token(&mut self, tier: MintCostTier) -> Result<()> {
+        require!(
+            !self.marketplace.paused.minting,
+            MarketplaceErrorCode::MintingPaused
+        );
+        assert_not_self_excluded(&self.user_account)?;
+        require!(
+            !self.marketplace.mint_requires_admin || self.admin.is_some(),
+            MarketplaceErrorCode::AdminSignatureRequired
+        );
         assert_valid_mint_tier_costs(tier)?;
 
-        let mint_tier = get_mint_tier(&self.marketplace.mint_tiers, tier);
+        let mint_tier = get_mint_tier(&self.marketplace.mint_tiers, tier)?;
+
+        track_spend_limit(&mut self.user_account, mint_tier.cost)?;
 
         let transfer_accounts = Transfer {
             from: self.user.to_account_info(),
@@ -80,9 +95,9 @@ impl<'info> MintBidToken<'info> {
         let bump = [self.marketplace.bump];
         let signer_seeds: [&[&[u8]]; 1] = [&[
             b"marketplace",
-            self.admin.to_account_info().key.as_ref(),
+            self.marketplace.creator.as_ref(),
             self.sbid_mint.to_account_info().key.as_ref(),
-            self.marketplace.name.as_str().as_bytes(),
+            self.marketplace.name.as_bytes(),
             &bump,
         ][..]];
 
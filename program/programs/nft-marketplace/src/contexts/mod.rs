@@ -1,17 +1,43 @@
+pub mod accept_admin;
+pub mod cancel_bid_agent;
+pub mod crank_bid_agent;
+pub mod create_bid_agent;
+pub mod delist;
 pub mod end_listing;
 pub mod initialize;
 pub mod initialize_user;
 pub mod list;
+pub mod migrate_marketplace;
+pub mod migrate_user_account;
 pub mod mint_bid_token;
 pub mod place_bid;
+pub mod propose_admin;
 pub mod purchase;
+pub mod self_exclude;
+pub mod set_bid_limits;
+pub mod settle_listing;
 pub mod update_marketplace;
+pub mod update_marketplace_token_metadata;
+pub mod withdraw_treasury;
 
+pub use accept_admin::*;
+pub use cancel_bid_agent::*;
+pub use crank_bid_agent::*;
+pub use create_bid_agent::*;
+pub use delist::*;
 pub use end_listing::*;
 pub use initialize::*;
 pub use initialize_user::*;
 pub use list::*;
+pub use migrate_marketplace::*;
+pub use migrate_user_account::*;
 pub use mint_bid_token::*;
 pub use place_bid::*;
+pub use propose_admin::*;
 pub use purchase::*;
+pub use self_exclude::*;
+pub use set_bid_limits::*;
+pub use settle_listing::*;
 pub use update_marketplace::*;
+pub use update_marketplace_token_metadata::*;
+pub use withdraw_treasury::*;
@@ -5,12 +5,13 @@ use anchor_spl::{
 };
 
 use crate::{
-    constants::{BID_PLACED_LABEL, REWARD_TIER_1},
-    events::BidPlaced,
-    state::{ListingV2, Marketplace, UserAccount},
+    constants::REWARD_TIER_1,
+    state::{BidHistory, ListingV2, Marketplace, UserAccount},
     utils::{
-        assert_already_highest_bidder, assert_auction_active,
-        assert_correct_highest_bidder_and_bid, MarketplaceErrorCode,
+        apply_bid, assert_already_highest_bidder, assert_auction_active,
+        assert_bid_history_provided, assert_correct_highest_bidder_and_bid,
+        assert_not_self_excluded, credit_paused_time, escrow_bid_vault, track_bid_limit,
+        transfer_sol, transfer_sol_from_bid_vault, MarketplaceErrorCode,
     },
 };
 
@@ -27,14 +28,17 @@ pub struct PlaceBid<'info> {
     ]
     pub user_account: Account<'info, UserAccount>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
     pub sbid_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
-        // TODO: Fix this ...
-        // associated_token::mint = sbid_mint,
-        // associated_token::authority = bidder,
+        token::mint = sbid_mint,
+        token::authority = bidder,
+        token::token_program = token_program,
     )]
     pub bidder_sbid_ata: InterfaceAccount<'info, TokenAccount>,
 
@@ -51,10 +55,34 @@ pub struct PlaceBid<'info> {
     )]
     listing: Box<Account<'info, ListingV2>>,
 
+    /// Missing on listings created before bid histories
+    #[account(
+        mut,
+        has_one = listing,
+        seeds = [b"bid_history", listing.key().as_ref()],
+        bump = bid_history.bump
+    )]
+    bid_history: Option<Box<Account<'info, BidHistory>>>,
+
+    /// Only used by `sol_escrow` listings
+    #[account(
+        mut,
+        seeds = [b"bid_vault", listing.key().as_ref()],
+        bump = listing.bid_vault_bump
+    )]
+    bid_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        address = listing.highest_bidder @ MarketplaceErrorCode::InvalidPreviousBidder
+    )]
+    /// CHECK: This is the outbid bidder - "address" constraint will take care of that.
+    previous_bidder: Option<UncheckedAccount<'info>>,
+
     #[account(
         mut,
         has_one = sbid_mint,
-        seeds = [b"marketplace", marketplace.admin.key().as_ref(), sbid_mint.key().as_ref(), marketplace.name.as_str().as_bytes()],
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
         bump = marketplace.bump
     )]
     marketplace: Account<'info, Marketplace>,
@@ -66,7 +94,14 @@ pub struct PlaceBid<'info> {
 
 impl<'info> PlaceBid<'info> {
     pub fn place_bid(&mut self, current_highest_bidder: &Pubkey, current_bid: &u64) -> Result<()> {
+        require!(
+            !self.marketplace.paused.bidding,
+            MarketplaceErrorCode::BiddingPaused
+        );
+        assert_not_self_excluded(&self.user_account)?;
+        credit_paused_time(&mut self.listing, &self.marketplace.bidding_pause)?;
         assert_auction_active(&self.listing)?;
+        assert_bid_history_provided(&self.listing, &self.bid_history)?;
         assert_correct_highest_bidder_and_bid(&self.listing, current_highest_bidder, current_bid)?;
         assert_already_highest_bidder(&self.listing, &self.bidder.to_account_info().key())?;
 
@@ -75,27 +110,52 @@ impl<'info> PlaceBid<'info> {
             MarketplaceErrorCode::BidderIsHighestBidder
         );
 
+        track_bid_limit(&mut self.user_account)?;
+
         // Transfer the bid token to the vault
         self.burn_token()?;
 
-        let auction = &mut self.listing;
-        auction.current_bid = auction
-            .current_bid
-            .checked_add(auction.bid_increment)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        auction.highest_bidder = self.bidder.key();
-        auction.end_time_in_slots = auction
-            .end_time_in_slots
-            .checked_add(auction.timer_extension_in_slots)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let previous_bidder = self.listing.highest_bidder;
+        let previous_bid = self.listing.current_bid;
 
-        emit!(BidPlaced {
-            bidder: self.bidder.key(),
-            listing: auction.key(),
-            current_bid: auction.current_bid,
-            end_time_in_slots: auction.end_time_in_slots,
-            label: BID_PLACED_LABEL.to_string(),
-        });
+        apply_bid(
+            &mut self.listing,
+            self.bid_history.as_mut(),
+            &self.marketplace.happy_hour,
+            &self.bidder.key(),
+        )?;
+
+        if self.listing.sol_escrow {
+            self.escrow_bid(&previous_bidder, previous_bid)?;
+        }
+
+        Ok(())
+    }
+
+    fn escrow_bid(&self, previous_bidder: &Pubkey, previous_bid: u64) -> Result<()> {
+        // Lock the new bid in the bid vault
+        transfer_sol(
+            self.bidder.to_account_info(),
+            escrow_bid_vault(&self.bid_vault)?,
+            self.system_program.to_account_info(),
+            self.listing.current_bid,
+        )?;
+
+        // Refund the outbid bidder
+        if *previous_bidder != Pubkey::default() {
+            let previous_bidder = self
+                .previous_bidder
+                .as_ref()
+                .ok_or(MarketplaceErrorCode::InvalidPreviousBidder)?;
+
+            transfer_sol_from_bid_vault(
+                &self.listing,
+                escrow_bid_vault(&self.bid_vault)?,
+                previous_bidder.to_account_info(),
+                self.system_program.to_account_info(),
+                previous_bid,
+            )?;
+        }
 
         Ok(())
     }
@@ -104,9 +164,9 @@ impl<'info> PlaceBid<'info> {
         let bump = [self.marketplace.bump];
         let signer_seeds: [&[&[u8]]; 1] = [&[
             b"marketplace",
-            self.marketplace.admin.as_ref(),
+            self.marketplace.creator.as_ref(),
             self.marketplace.sbid_mint.as_ref(),
-            self.marketplace.name.as_str().as_bytes(),
+            self.marketplace.name.as_bytes(),
             &bump,
         ][..]];
 
@@ -122,18 +182,7 @@ impl<'info> PlaceBid<'info> {
             &signer_seeds,
         );
 
-        let decimals = self.sbid_mint.decimals;
-        let amount = self
-            .listing
-            .bid_cost
-            .checked_mul(
-                10u64
-                    .checked_pow(decimals as u32)
-                    .ok_or(ProgramError::ArithmeticOverflow)?,
-            )
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-
-        burn(cpi_context, amount)?;
+        burn(cpi_context, self.listing.bid_cost_amount()?)?;
 
         Ok(())
     }
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Marketplace;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump
+    )]
+    marketplace: Account<'info, Marketplace>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    /// Proposing the default pubkey cancels a pending handover
+    pub fn propose_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        self.marketplace.pending_admin = new_admin;
+
+        Ok(())
+    }
+}
@@ -1,37 +1,34 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    metadata::Metadata,
+    metadata::{MasterEditionAccount, Metadata, MetadataAccount},
     token::Token,
-    token_interface::{
-        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TransferChecked,
-    },
+    token_interface::{close_account, CloseAccount, Mint, TokenAccount},
 };
-use solana_program::system_instruction;
 
-use crate::state::{ListingV2, Marketplace};
+use crate::{
+    constants::LISTING_PURCHASED_LABEL,
+    events::ListingPurchased,
+    state::{BidHistory, ListingV2, Marketplace},
+    transfer::transfer_asset,
+    utils::{
+        assert_bid_history_provided, assert_buyout_available, close_bid_vault, credit_paused_time,
+        escrow_bid_vault, split_marketplace_fee, transfer_sol, transfer_sol_from_bid_vault,
+        MarketplaceErrorCode,
+    },
+};
 
 #[derive(Accounts)]
 pub struct Purchase<'info> {
     #[account(mut)]
     buyer: Signer<'info>,
 
-    #[account(mut)]
-    /// CHECK: I dunno why Anchor is complaining about this one.
-    /// I added the address field to the account attribute.
-    seller: SystemAccount<'info>,
-
-    #[account(
-        address = listing.mint
-    )]
-    pub mint: InterfaceAccount<'info, Mint>,
-
     #[account(
         mut,
-        associated_token::mint = mint,
-        associated_token::authority = seller,
+        address = listing.seller
     )]
-    maker_ata: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: This is the seller - "address" constraint will take care of that.
+    seller: AccountInfo<'info>,
 
     #[account(
         init_if_needed,
@@ -39,36 +36,81 @@ pub struct Purchase<'info> {
         associated_token::mint = mint,
         associated_token::authority = buyer,
     )]
-    taker_ata: InterfaceAccount<'info, TokenAccount>,
+    buyer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        address = listing.mint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub collection: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
         close = seller,
-        has_one = seller,
         has_one = mint,
         seeds = [b"listing", marketplace.key().as_ref(), listing.mint.key().as_ref(), listing.seed.to_le_bytes().as_ref()],
         bump = listing.bump
     )]
     listing: Box<Account<'info, ListingV2>>,
 
+    /// Missing on listings created before bid histories
     #[account(
         mut,
-        seeds = [b"marketplace", marketplace.admin.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_str().as_bytes()],
-        bump = marketplace.bump
+        close = seller,
+        has_one = listing,
+        seeds = [b"bid_history", listing.key().as_ref()],
+        bump = bid_history.bump
+    )]
+    bid_history: Option<Box<Account<'info, BidHistory>>>,
+
+    /// Only used by `sol_escrow` listings
+    #[account(
+        mut,
+        seeds = [b"bid_vault", listing.key().as_ref()],
+        bump = listing.bid_vault_bump
     )]
-    marketplace: Account<'info, Marketplace>,
+    bid_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        address = listing.highest_bidder @ MarketplaceErrorCode::InvalidPreviousBidder
+    )]
+    /// CHECK: This is the outbid bidder - "address" constraint will take care of that.
+    previous_bidder: Option<UncheckedAccount<'info>>,
 
     #[account(
         mut,
         associated_token::mint = mint,
         associated_token::authority = listing,
     )]
-    vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    escrow: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
-        seeds = [b"treasury", marketplace.key().as_ref()],
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = metadata_program.key(),
         bump,
+        constraint = metadata.collection.as_ref().is_some_and(|c| c.key == collection.key() && c.verified),
+    )]
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+
+    #[account(
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref(), b"edition"],
+        seeds::program = metadata_program.key(),
+        bump
+    )]
+    pub master_edition: Box<Account<'info, MasterEditionAccount>>,
+
+    #[account(
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump
+    )]
+    marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", marketplace.key().as_ref()],
+        bump = marketplace.treasury_bump,
     )]
     pub treasury: SystemAccount<'info>,
 
@@ -76,91 +118,116 @@ pub struct Purchase<'info> {
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub metadata_program: Program<'info, Metadata>,
+    /// CHECK: The sysvar instructions account. This account is checked in metadata transfer
+    pub sysvar_instructions: UncheckedAccount<'info>,
 }
 
 impl<'info> Purchase<'info> {
-    pub fn purchase(&mut self) -> Result<()> {
-        let amount_to_treasury = self
-            .listing
-            .buyout_price
-            .checked_mul(self.marketplace.fee as u64)
-            .ok_or(ProgramError::ArithmeticOverflow)?
-            .checked_div(10000)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-
-        // Transfer the amount to the treasury.
-        self.transfer_sol(
-            &self.buyer.to_account_info(),
-            &self.treasury.to_account_info(),
+    pub fn purchase<'a>(
+        &mut self,
+        amount: u64,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<()> {
+        // A purchase both outbids everyone and settles the listing
+        require!(
+            !self.marketplace.paused.bidding,
+            MarketplaceErrorCode::BiddingPaused
+        );
+        require!(
+            !self.marketplace.paused.settlement,
+            MarketplaceErrorCode::SettlementPaused
+        );
+        assert_bid_history_provided(&self.listing, &self.bid_history)?;
+        credit_paused_time(&mut self.listing, &self.marketplace.bidding_pause)?;
+        assert_buyout_available(&self.listing)?;
+
+        let (amount_to_treasury, amount_to_seller) =
+            split_marketplace_fee(self.listing.buyout_price, self.marketplace.fee)?;
+
+        // Transfer the marketplace fee to treasury
+        transfer_sol(
+            self.buyer.to_account_info(),
+            self.treasury.to_account_info(),
+            self.system_program.to_account_info(),
             amount_to_treasury,
         )?;
 
-        let amount_to_maker = self
-            .listing
-            .buyout_price
-            .checked_sub(amount_to_treasury)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-
-        // Transfer the remaining amount to the seller.
-        self.transfer_sol(
-            &self.buyer.to_account_info(),
-            &self.seller.to_account_info(),
-            amount_to_maker,
+        // Transfer the remaining amount to the seller
+        transfer_sol(
+            self.buyer.to_account_info(),
+            self.seller.to_account_info(),
+            self.system_program.to_account_info(),
+            amount_to_seller,
         )?;
 
-        // Transfer the NFT to the buyer and close vault and listing account.
-        self.withdraw_nft_and_close()
-    }
-
-    fn transfer_sol(
-        &self,
-        from: &AccountInfo<'info>,
-        to: &AccountInfo<'info>,
-        amount: u64,
-    ) -> Result<()> {
-        // Create the transfer instruction
-        let transfer_instruction = system_instruction::transfer(from.key, to.key, amount);
-
-        // Invoke the transfer instruction
-        anchor_lang::solana_program::program::invoke(
-            &transfer_instruction,
-            &[
-                from.to_account_info(),
-                to.to_account_info(),
+        // Refund the escrowed bid of the current highest bidder
+        if self.listing.sol_escrow && self.listing.highest_bidder != Pubkey::default() {
+            let previous_bidder = self
+                .previous_bidder
+                .as_ref()
+                .ok_or(MarketplaceErrorCode::InvalidPreviousBidder)?;
+
+            transfer_sol_from_bid_vault(
+                &self.listing,
+                escrow_bid_vault(&self.bid_vault)?,
+                previous_bidder.to_account_info(),
                 self.system_program.to_account_info(),
-            ],
-        )?;
+                self.listing.current_bid,
+            )?;
+        }
+
+        // Transfer the NFT to the buyer, the listing account is closed by anchor
+        self.withdraw_and_close(amount, remaining_accounts)?;
+
+        self.listing.is_active = false;
+
+        emit!(ListingPurchased {
+            listing_pubkey: self.listing.key(),
+            buyer: self.buyer.key(),
+            buyout_price: self.listing.buyout_price,
+            amount_to_treasury,
+            amount_to_seller,
+            label: LISTING_PURCHASED_LABEL.to_string(),
+        });
 
         Ok(())
     }
 
-    pub fn withdraw_nft_and_close(&mut self) -> Result<()> {
+    pub fn withdraw_and_close<'a>(
+        &mut self,
+        amount: u64,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<()> {
         let bump = [self.listing.bump];
+        let seed = self.listing.seed.to_le_bytes();
         let signer_seeds = [&[
             b"listing",
             self.marketplace.to_account_info().key.as_ref(),
             self.mint.to_account_info().key.as_ref(),
+            seed.as_ref(),
             &bump,
         ][..]];
 
-        let accounts = TransferChecked {
-            from: self.vault.to_account_info(),
-            to: self.taker_ata.to_account_info(),
-            mint: self.mint.to_account_info(),
-            authority: self.listing.to_account_info(),
-        };
-
-        let cpi_context = CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            accounts,
-            &signer_seeds,
-        );
-
-        transfer_checked(cpi_context, 1, self.mint.decimals)?;
+        transfer_asset(
+            amount,
+            &self.escrow.to_account_info(),
+            &self.buyer_ata.to_account_info(),
+            &self.listing.to_account_info(),
+            &self.buyer.to_account_info(),
+            &self.buyer.to_account_info(),
+            &self.mint,
+            &self.metadata.to_account_info(),
+            &self.token_program,
+            &self.system_program,
+            &self.associated_token_program,
+            &self.sysvar_instructions,
+            remaining_accounts,
+            Some(signer_seeds),
+        )?;
 
-        // Close the vault account
+        // Close the escrow account
         let accounts = CloseAccount {
-            account: self.vault.to_account_info(),
+            account: self.escrow.to_account_info(),
             destination: self.seller.to_account_info(),
             authority: self.listing.to_account_info(),
         };
@@ -171,6 +238,14 @@ impl<'info> Purchase<'info> {
             &signer_seeds,
         );
 
-        close_account(ctx)
+        close_account(ctx)?;
+
+        // Return the rent funded into the bid vault on list
+        close_bid_vault(
+            &self.listing,
+            &self.bid_vault,
+            self.seller.to_account_info(),
+            self.system_program.to_account_info(),
+        )
     }
 }
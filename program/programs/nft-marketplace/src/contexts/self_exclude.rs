@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::USER_SELF_EXCLUDED_LABEL,
+    events::UserSelfExcluded,
+    state::{ExclusionPeriod, Marketplace, UserAccount},
+};
+
+#[derive(Accounts)]
+pub struct SelfExclude<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user", marketplace.key().as_ref(), user.key().as_ref()],
+        bump = user_account.bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump,
+    )]
+    marketplace: Account<'info, Marketplace>,
+}
+
+impl<'info> SelfExclude<'info> {
+    pub fn self_exclude(&mut self, period: ExclusionPeriod) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let excluded_until = now
+            .checked_add(period.duration_in_secs())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        // An exclusion in effect can only be extended, never shortened
+        self.user_account.excluded_until = self.user_account.excluded_until.max(excluded_until);
+
+        emit!(UserSelfExcluded {
+            user_pubkey: self.user.key(),
+            excluded_from: now,
+            excluded_until: self.user_account.excluded_until,
+            label: USER_SELF_EXCLUDED_LABEL.to_string()
+        });
+
+        Ok(())
+    }
+}
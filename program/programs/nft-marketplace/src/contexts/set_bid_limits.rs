@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{BidLimits, Marketplace, UserAccount};
+
+#[derive(Accounts)]
+pub struct SetBidLimits<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user", marketplace.key().as_ref(), user.key().as_ref()],
+        bump = user_account.bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump,
+    )]
+    marketplace: Account<'info, Marketplace>,
+}
+
+impl<'info> SetBidLimits<'info> {
+    pub fn set_bid_limits(&mut self, limits: BidLimits) -> Result<()> {
+        self.user_account
+            .roll_limit_window(Clock::get()?.unix_timestamp);
+
+        // Usage in the current window is kept and looser limits only apply once
+        // it has elapsed, so the limits can't be lifted in the middle of a window
+        if self.user_account.bid_limits.is_tightened_by(&limits) {
+            self.user_account.bid_limits = limits;
+            self.user_account.pending_bid_limits = None;
+        } else {
+            self.user_account.pending_bid_limits = Some(limits);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,361 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{create_idempotent, AssociatedToken, Create},
+    metadata::{MasterEditionAccount, Metadata, MetadataAccount},
+    token::Token,
+    token_interface::{close_account, CloseAccount, Mint, TokenAccount},
+};
+
+use crate::{
+    constants::{LISTING_ENDED_LABEL, LISTING_SETTLED_LABEL, PENALTY_UNPAID_AUCTION},
+    events::{ListingEnded, ListingSettled},
+    state::{BidHistory, ListingEndOutcome, ListingV2, Marketplace, UserAccount},
+    transfer::transfer_asset,
+    utils::{
+        assert_bid_history_provided, assert_not_awaiting_pause_credit,
+        assert_settlement_grace_expired, close_bid_vault, credit_paused_time, escrow_bid_vault,
+        is_reserve_met, split_marketplace_fee, transfer_sol_from_bid_vault, MarketplaceErrorCode,
+    },
+};
+
+#[derive(Accounts)]
+pub struct SettleListing<'info> {
+    #[account(mut)]
+    cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        address = listing.seller
+    )]
+    /// CHECK: This is the seller - "address" constraint will take care of that.
+    seller: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+    )]
+    seller_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Required to penalize a winner who did not pay
+    #[account(
+        mut,
+        seeds = [b"user", marketplace.key().as_ref(), listing.highest_bidder.key().as_ref()],
+        bump
+    )]
+    /// CHECK: The winner's user account PDA, it is only penalized once it has been
+    /// created and migrated since a legacy layout can't be deserialized
+    pub winner_account: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        address = listing.highest_bidder @ MarketplaceErrorCode::InvalidWinnerAccount
+    )]
+    /// CHECK: This is the winner - "address" constraint will take care of that.
+    /// Required when the highest bid is locked in the bid vault.
+    winner: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: The winner's ATA, it is created if missing and validated by the associated token program
+    winner_ata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        address = listing.mint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        close = seller,
+        has_one = mint,
+        seeds = [b"listing", marketplace.key().as_ref(), listing.mint.key().as_ref(), listing.seed.to_le_bytes().as_ref()],
+        bump = listing.bump
+    )]
+    listing: Box<Account<'info, ListingV2>>,
+
+    /// Missing on listings created before bid histories
+    #[account(
+        mut,
+        close = seller,
+        has_one = listing,
+        seeds = [b"bid_history", listing.key().as_ref()],
+        bump = bid_history.bump
+    )]
+    bid_history: Option<Box<Account<'info, BidHistory>>>,
+
+    /// Only used by `sol_escrow` listings
+    #[account(
+        mut,
+        seeds = [b"bid_vault", listing.key().as_ref()],
+        bump = listing.bid_vault_bump
+    )]
+    bid_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+    )]
+    escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+
+    #[account(
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref(), b"edition"],
+        seeds::program = metadata_program.key(),
+        bump
+    )]
+    pub master_edition: Box<Account<'info, MasterEditionAccount>>,
+
+    #[account(
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump
+    )]
+    marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", marketplace.key().as_ref()],
+        bump = marketplace.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub metadata_program: Program<'info, Metadata>,
+    /// CHECK: The sysvar instructions account. This account is checked in metadata transfer
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+impl<'info> SettleListing<'info> {
+    pub fn settle_listing<'a>(
+        &mut self,
+        amount: u64,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            self.listing.is_active,
+            MarketplaceErrorCode::AuctionNotActive
+        );
+        require!(
+            !self.marketplace.paused.settlement,
+            MarketplaceErrorCode::SettlementPaused
+        );
+        assert_bid_history_provided(&self.listing, &self.bid_history)?;
+        credit_paused_time(&mut self.listing, &self.marketplace.bidding_pause)?;
+        assert_not_awaiting_pause_credit(&self.listing, &self.marketplace)?;
+        assert_settlement_grace_expired(&self.listing, self.marketplace.settlement_grace_in_slots)?;
+
+        let has_bids = self.listing.highest_bidder != Pubkey::default();
+        let has_winner = has_bids && is_reserve_met(&self.listing);
+
+        if has_winner && self.listing.sol_escrow {
+            return self.settle_escrowed_bid(amount, remaining_accounts);
+        }
+
+        if has_winner {
+            self.penalize_winner()?;
+        } else if has_bids && self.listing.sol_escrow {
+            self.refund_escrowed_bid()?;
+        }
+
+        // Return the NFT to the seller, the listing account is closed by anchor
+        self.withdraw_and_close(
+            amount,
+            self.seller_ata.to_account_info(),
+            self.seller.to_account_info(),
+            remaining_accounts,
+        )?;
+
+        self.listing.is_active = false;
+
+        emit!(ListingSettled {
+            listing_pubkey: self.listing.key(),
+            mint: self.listing.mint,
+            seller: self.listing.seller,
+            unpaid_winner: if has_winner {
+                self.listing.highest_bidder
+            } else {
+                Pubkey::default()
+            },
+            unpaid_bid: self.listing.current_bid,
+            label: LISTING_SETTLED_LABEL.to_string(),
+        });
+
+        Ok(())
+    }
+
+    /// The winner has already paid, complete the sale from the bid vault
+    fn settle_escrowed_bid<'a>(
+        &mut self,
+        amount: u64,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<()> {
+        let winner = self
+            .winner
+            .as_ref()
+            .ok_or(MarketplaceErrorCode::InvalidWinnerAccount)?
+            .to_account_info();
+        let winner_ata = self
+            .winner_ata
+            .as_ref()
+            .ok_or(MarketplaceErrorCode::InvalidWinnerAccount)?
+            .to_account_info();
+
+        let (amount_to_treasury, amount_to_seller) =
+            split_marketplace_fee(self.listing.current_bid, self.marketplace.fee)?;
+
+        transfer_sol_from_bid_vault(
+            &self.listing,
+            escrow_bid_vault(&self.bid_vault)?,
+            self.treasury.to_account_info(),
+            self.system_program.to_account_info(),
+            amount_to_treasury,
+        )?;
+        transfer_sol_from_bid_vault(
+            &self.listing,
+            escrow_bid_vault(&self.bid_vault)?,
+            self.seller.to_account_info(),
+            self.system_program.to_account_info(),
+            amount_to_seller,
+        )?;
+
+        create_idempotent(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            Create {
+                payer: self.cranker.to_account_info(),
+                associated_token: winner_ata.clone(),
+                authority: winner.clone(),
+                mint: self.mint.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        ))?;
+
+        // Transfer the NFT to the winner, the listing account is closed by anchor
+        self.withdraw_and_close(amount, winner_ata, winner, remaining_accounts)?;
+
+        self.listing.is_active = false;
+
+        emit!(ListingEnded {
+            listing_pubkey: self.listing.key(),
+            mint: self.listing.mint,
+            seller: self.listing.seller,
+            winner: self.listing.highest_bidder,
+            winning_bid: self.listing.current_bid,
+            amount_to_treasury,
+            amount_to_seller,
+            outcome: ListingEndOutcome::Sold,
+            label: LISTING_ENDED_LABEL.to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_and_close<'a>(
+        &mut self,
+        amount: u64,
+        to: AccountInfo<'info>,
+        to_authority: AccountInfo<'info>,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<()> {
+        let bump = [self.listing.bump];
+        let seed = self.listing.seed.to_le_bytes();
+        let signer_seeds = [&[
+            b"listing",
+            self.marketplace.to_account_info().key.as_ref(),
+            self.mint.to_account_info().key.as_ref(),
+            seed.as_ref(),
+            &bump,
+        ][..]];
+
+        transfer_asset(
+            amount,
+            &self.escrow.to_account_info(),
+            &to,
+            &self.listing.to_account_info(),
+            &to_authority,
+            &self.cranker.to_account_info(),
+            &self.mint,
+            &self.metadata.to_account_info(),
+            &self.token_program,
+            &self.system_program,
+            &self.associated_token_program,
+            &self.sysvar_instructions,
+            remaining_accounts,
+            Some(signer_seeds),
+        )?;
+
+        // Close the escrow account
+        let accounts = CloseAccount {
+            account: self.escrow.to_account_info(),
+            destination: self.seller.to_account_info(),
+            authority: self.listing.to_account_info(),
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            accounts,
+            &signer_seeds,
+        );
+
+        close_account(ctx)?;
+
+        // Return the rent funded into the bid vault on list
+        close_bid_vault(
+            &self.listing,
+            &self.bid_vault,
+            self.seller.to_account_info(),
+            self.system_program.to_account_info(),
+        )
+    }
+
+    /// The reserve price wasn't met, the highest bidder gets the escrowed bid back
+    fn refund_escrowed_bid(&mut self) -> Result<()> {
+        let highest_bidder = self
+            .winner
+            .as_ref()
+            .ok_or(MarketplaceErrorCode::InvalidWinnerAccount)?;
+
+        transfer_sol_from_bid_vault(
+            &self.listing,
+            escrow_bid_vault(&self.bid_vault)?,
+            highest_bidder.to_account_info(),
+            self.system_program.to_account_info(),
+            self.listing.current_bid,
+        )
+    }
+
+    pub fn penalize_winner(&mut self) -> Result<()> {
+        let winner_info = self
+            .winner_account
+            .as_ref()
+            .ok_or(MarketplaceErrorCode::InvalidWinnerAccount)?
+            .to_account_info();
+
+        // Settlement must not depend on the winner, skip those without an up to date account
+        if winner_info.owner != &crate::ID || winner_info.data_len() < 8 + UserAccount::INIT_SPACE {
+            return Ok(());
+        }
+
+        let mut winner_account =
+            UserAccount::try_deserialize(&mut &winner_info.try_borrow_data()?[..])?;
+
+        winner_account.points = winner_account.points.saturating_sub(PENALTY_UNPAID_AUCTION);
+        winner_account.total_auctions_unpaid = winner_account
+            .total_auctions_unpaid
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        winner_account.try_serialize(&mut &mut winner_info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
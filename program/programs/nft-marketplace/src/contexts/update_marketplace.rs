@@ -1,6 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Marketplace, MintTier};
+use crate::{
+    constants::{MARKETPLACE_PAUSED_LABEL, MARKETPLACE_UPDATED_LABEL, MAX_MARKETPLACE_FEE_BPS},
+    events::{MarketplacePaused, MarketplaceUpdated},
+    state::{
+        HappyHour, ListingBounds, Marketplace, MarketplaceUpdate, MintTier, PauseFlags, PauseTime,
+    },
+    utils::{assert_valid_happy_hour, assert_valid_mint_tiers, MarketplaceErrorCode},
+};
 
 #[derive(Accounts)]
 pub struct UpdateMarketplace<'info> {
@@ -10,7 +17,7 @@ pub struct UpdateMarketplace<'info> {
     #[account(
         mut,
         has_one = admin,
-        seeds = [b"marketplace", marketplace.admin.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_str().as_bytes()],
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
         bump = marketplace.bump
     )]
     marketplace: Account<'info, Marketplace>,
@@ -20,8 +27,129 @@ pub struct UpdateMarketplace<'info> {
 
 impl<'info> UpdateMarketplace<'info> {
     pub fn update_mint_tiers(&mut self, tiers: [MintTier; 3]) -> Result<()> {
+        assert_valid_mint_tiers(&tiers)?;
+
         self.marketplace.mint_tiers = tiers;
 
         Ok(())
     }
+
+    pub fn update_happy_hour(&mut self, happy_hour: HappyHour) -> Result<()> {
+        assert_valid_happy_hour(&happy_hour)?;
+
+        self.marketplace.happy_hour = happy_hour;
+
+        Ok(())
+    }
+
+    pub fn update_settlement_grace(&mut self, grace_in_slots: u64) -> Result<()> {
+        self.marketplace.settlement_grace_in_slots = grace_in_slots;
+
+        Ok(())
+    }
+
+    pub fn update_bid_cost_bounds(&mut self, min_bid_cost: u64, max_bid_cost: u64) -> Result<()> {
+        require!(
+            min_bid_cost > 0 && min_bid_cost <= max_bid_cost,
+            MarketplaceErrorCode::InvalidBidCostBounds
+        );
+
+        let old = self.marketplace.bid_cost_bounds();
+        self.marketplace.min_bid_cost = min_bid_cost;
+        self.marketplace.max_bid_cost = max_bid_cost;
+
+        emit!(MarketplaceUpdated {
+            marketplace: self.marketplace.key(),
+            update: MarketplaceUpdate::BidCostBounds {
+                old,
+                new: self.marketplace.bid_cost_bounds(),
+            },
+            label: MARKETPLACE_UPDATED_LABEL.to_string()
+        });
+
+        Ok(())
+    }
+
+    pub fn update_listing_bounds(&mut self, bounds: ListingBounds) -> Result<()> {
+        require!(
+            bounds.min_bid_increment > 0
+                && bounds.min_bid_increment <= bounds.max_bid_increment
+                && bounds.min_timer_extension_in_slots <= bounds.max_timer_extension_in_slots
+                && bounds.max_duration_in_slots > 0,
+            MarketplaceErrorCode::InvalidListingBounds
+        );
+
+        let old = self.marketplace.listing_bounds;
+        self.marketplace.listing_bounds = bounds;
+
+        emit!(MarketplaceUpdated {
+            marketplace: self.marketplace.key(),
+            update: MarketplaceUpdate::ListingBounds { old, new: bounds },
+            label: MARKETPLACE_UPDATED_LABEL.to_string()
+        });
+
+        Ok(())
+    }
+
+    pub fn update_fee(&mut self, fee: u16) -> Result<()> {
+        require!(
+            fee <= MAX_MARKETPLACE_FEE_BPS,
+            MarketplaceErrorCode::InvalidMarketplaceFee
+        );
+
+        let old = self.marketplace.fee;
+        self.marketplace.fee = fee;
+
+        emit!(MarketplaceUpdated {
+            marketplace: self.marketplace.key(),
+            update: MarketplaceUpdate::Fee { old, new: fee },
+            label: MARKETPLACE_UPDATED_LABEL.to_string()
+        });
+
+        Ok(())
+    }
+
+    pub fn update_mint_gate(&mut self, mint_requires_admin: bool) -> Result<()> {
+        self.marketplace.mint_requires_admin = mint_requires_admin;
+
+        Ok(())
+    }
+
+    pub fn set_pause(&mut self, paused: PauseFlags) -> Result<()> {
+        let now = PauseTime::now(&Clock::get()?);
+        let was_bidding_paused = self.marketplace.paused.bidding;
+        let pause = &mut self.marketplace.bidding_pause;
+
+        if paused.bidding && !was_bidding_paused {
+            pause.started_at = now;
+        } else if !paused.bidding && was_bidding_paused {
+            // Running auctions get this pause credited the next time they are used
+            pause.last_duration = PauseTime {
+                slots: now.slots.saturating_sub(pause.started_at.slots),
+                seconds: now.seconds.saturating_sub(pause.started_at.seconds),
+            };
+            pause.total_duration = PauseTime {
+                slots: pause
+                    .total_duration
+                    .slots
+                    .checked_add(pause.last_duration.slots)
+                    .ok_or(ProgramError::ArithmeticOverflow)?,
+                seconds: pause
+                    .total_duration
+                    .seconds
+                    .checked_add(pause.last_duration.seconds)
+                    .ok_or(ProgramError::ArithmeticOverflow)?,
+            };
+        }
+
+        self.marketplace.paused = paused;
+
+        emit!(MarketplacePaused {
+            marketplace: self.marketplace.key(),
+            paused,
+            label: MARKETPLACE_PAUSED_LABEL.to_string()
+        });
+
+        Ok(())
+    }
 }
@@ -0,0 +1,146 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        extension::{BaseStateWithExtensions, PodStateWithExtensions},
+        pod::PodMint,
+    },
+    token_interface::{
+        spl_token_metadata_interface::{self, state::Field, state::TokenMetadata},
+        Mint, Token2022,
+    },
+};
+
+use solana_program::program::invoke_signed;
+
+use crate::{
+    constants::MARKETPLACE_UPDATED_LABEL,
+    events::MarketplaceUpdated,
+    state::{Marketplace, MarketplaceUpdate, SbidMetadata},
+};
+
+#[derive(Accounts)]
+pub struct UpdateMarketplaceTokenMetadata<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        has_one = admin,
+        has_one = sbid_mint,
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump
+    )]
+    marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        mut,
+        mint::token_program = token_program_2022,
+    )]
+    sbid_mint: InterfaceAccount<'info, Mint>,
+
+    token_program_2022: Program<'info, Token2022>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateMarketplaceTokenMetadata<'info> {
+    pub fn update_token_metadata(
+        &mut self,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        let (old_metadata, old_len, account_len) = {
+            let mint_info = self.sbid_mint.to_account_info();
+            let data = mint_info.try_borrow_data()?;
+            let mint = PodStateWithExtensions::<PodMint>::unpack(&data)?;
+            let metadata = mint.get_variable_len_extension::<TokenMetadata>()?;
+            let old_len = metadata.tlv_size_of()?;
+
+            (metadata, old_len, data.len())
+        };
+
+        let mut new_metadata = old_metadata.clone();
+        new_metadata.update(Field::Name, name.clone());
+        new_metadata.update(Field::Symbol, symbol.clone());
+        new_metadata.update(Field::Uri, uri.clone());
+
+        // The token program reallocs the mint to fit the new metadata but
+        // doesn't fund it, top up the rent when the metadata grows
+        let new_account_len = account_len
+            .checked_sub(old_len)
+            .and_then(|len| len.checked_add(new_metadata.tlv_size_of().ok()?))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let top_up = (Rent::get()?)
+            .minimum_balance(new_account_len)
+            .saturating_sub(self.sbid_mint.to_account_info().lamports());
+
+        if top_up > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.admin.to_account_info(),
+                        to: self.sbid_mint.to_account_info(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+
+        let bump = [self.marketplace.bump];
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"marketplace",
+            self.marketplace.creator.as_ref(),
+            self.marketplace.sbid_mint.as_ref(),
+            self.marketplace.name.as_bytes(),
+            &bump,
+        ][..]];
+
+        for (field, value) in [
+            (Field::Name, name),
+            (Field::Symbol, symbol),
+            (Field::Uri, uri),
+        ] {
+            let update_field_inst = spl_token_metadata_interface::instruction::update_field(
+                self.token_program_2022.key,
+                &self.sbid_mint.key(),
+                &self.marketplace.key(),
+                field,
+                value,
+            );
+
+            invoke_signed(
+                &update_field_inst,
+                &[
+                    // metadata_info
+                    self.sbid_mint.to_account_info(),
+                    // update_authority_info
+                    self.marketplace.to_account_info(),
+                ],
+                &signer_seeds,
+            )?;
+        }
+
+        emit!(MarketplaceUpdated {
+            marketplace: self.marketplace.key(),
+            update: MarketplaceUpdate::TokenMetadata {
+                old: SbidMetadata {
+                    name: old_metadata.name,
+                    symbol: old_metadata.symbol,
+                    uri: old_metadata.uri,
+                },
+                new: SbidMetadata {
+                    name: new_metadata.name,
+                    symbol: new_metadata.symbol,
+                    uri: new_metadata.uri,
+                },
+            },
+            label: MARKETPLACE_UPDATED_LABEL.to_string()
+        });
+
+        Ok(())
+    }
+}
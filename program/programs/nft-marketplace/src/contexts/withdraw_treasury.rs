@@ -0,0 +1,75 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    constants::TREASURY_WITHDRAWN_LABEL, errors::MarketplaceErrorCode, events::TreasuryWithdrawn,
+    state::Marketplace,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        has_one = admin,
+        has_one = treasury,
+        seeds = [b"marketplace", marketplace.creator.key().as_ref(), marketplace.sbid_mint.key().as_ref(), marketplace.name.as_bytes()],
+        bump = marketplace.bump
+    )]
+    marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", marketplace.key().as_ref()],
+        bump = marketplace.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: any account can receive SOL from the treasury
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawTreasury<'info> {
+    pub fn withdraw_treasury(&mut self, amount: u64) -> Result<()> {
+        // The treasury must stay rent exempt, otherwise it gets garbage collected
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+        let withdrawable = self.treasury.lamports().saturating_sub(rent_exempt_minimum);
+
+        require!(
+            amount > 0 && amount <= withdrawable,
+            MarketplaceErrorCode::InsufficientTreasuryBalance
+        );
+
+        let marketplace_key = self.marketplace.key();
+        let bump = [self.marketplace.treasury_bump];
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"treasury", marketplace_key.as_ref(), &bump][..]];
+
+        let transfer_accounts = Transfer {
+            from: self.treasury.to_account_info(),
+            to: self.destination.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new_with_signer(
+            self.system_program.to_account_info(),
+            transfer_accounts,
+            &signer_seeds,
+        );
+
+        transfer(cpi_context, amount)?;
+
+        emit!(TreasuryWithdrawn {
+            marketplace: marketplace_key,
+            destination: self.destination.key(),
+            amount,
+            label: TREASURY_WITHDRAWN_LABEL.to_string()
+        });
+
+        Ok(())
+    }
+}
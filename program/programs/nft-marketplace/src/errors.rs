@@ -41,4 +41,98 @@ pub enum MarketplaceErrorCode {
 
     #[msg("Invalid mint const")]
     InvalidMintCost,
+
+    #[msg("Insufficient treasury balance")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Listing has no buyout price")]
+    BuyoutNotAvailable,
+
+    #[msg("Bid limit for the current period has been reached")]
+    BidLimitReached,
+    #[msg("Spending limit for the current period has been reached")]
+    SpendLimitReached,
+
+    #[msg("User is self excluded")]
+    UserSelfExcluded,
+
+    #[msg("Invalid happy hour configuration")]
+    InvalidHappyHour,
+
+    #[msg("Invalid bid agent configuration")]
+    InvalidBidAgent,
+    #[msg("Bid agent has placed its maximum number of bids")]
+    BidAgentMaxBidsReached,
+    #[msg("Bid agent max price would be exceeded")]
+    BidAgentMaxPriceReached,
+    #[msg("Auction is outside of the bid agent bidding window")]
+    BidAgentOutsideBidWindow,
+    #[msg("Bid agent deposit is too low for another bid")]
+    BidAgentInsufficientDeposit,
+
+    #[msg("Marketplace requires the admin to co-sign sBid purchases")]
+    AdminSignatureRequired,
+
+    #[msg("Winner can still pay during the settlement grace period")]
+    SettlementGracePeriodActive,
+    #[msg("Settlement grace period has expired")]
+    SettlementGracePeriodExpired,
+    #[msg("Invalid winner user account")]
+    InvalidWinnerAccount,
+
+    #[msg("Bid agents are not supported on SOL escrow listings")]
+    SolEscrowNotSupported,
+    #[msg("Invalid previous highest bidder account")]
+    InvalidPreviousBidder,
+
+    #[msg("Reserve price can't be above the buyout price")]
+    InvalidReservePrice,
+
+    #[msg("Bid cost is outside of the marketplace bounds")]
+    InvalidBidCost,
+    #[msg("Invalid bid cost bounds")]
+    InvalidBidCostBounds,
+
+    #[msg("Capped timer max end time is before the auction end time")]
+    InvalidTimerMode,
+
+    #[msg("Bid increment is outside of the marketplace bounds")]
+    InvalidBidIncrement,
+    #[msg("Timer extension is outside of the marketplace bounds")]
+    InvalidTimerExtension,
+    #[msg("Listing duration is above the marketplace maximum")]
+    InvalidListingDuration,
+    #[msg("Listing can't start before the current time")]
+    InvalidListingStartTime,
+    #[msg("Buyout price must be above the first bid")]
+    InvalidBuyoutPrice,
+    #[msg("Invalid listing bounds")]
+    InvalidListingBounds,
+
+    #[msg("Signer is not the pending admin")]
+    InvalidPendingAdmin,
+
+    #[msg("Listing is paused")]
+    ListingPaused,
+    #[msg("Bidding is paused")]
+    BiddingPaused,
+    #[msg("Minting is paused")]
+    MintingPaused,
+    #[msg("Settlement is paused")]
+    SettlementPaused,
+
+    #[msg("Marketplace fee is above the maximum")]
+    InvalidMarketplaceFee,
+
+    #[msg("Each mint tier must be set exactly once")]
+    InvalidMintTiers,
+    #[msg("Mint tier amount and cost must be above zero")]
+    InvalidMintTierAmount,
+    #[msg("Mint tier bonus is above the maximum")]
+    InvalidMintTierBonus,
+
+    #[msg("Listing has a bid history which must be provided")]
+    MissingBidHistory,
+    #[msg("Listing escrows bids in a bid vault which must be provided")]
+    MissingBidVault,
 }
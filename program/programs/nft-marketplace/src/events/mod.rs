@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{ListingEndOutcome, MarketplaceUpdate, PauseFlags};
+
 #[event]
 pub struct MarketplaceInitialized {
     pub marketplace_pubkey: Pubkey,
@@ -24,6 +26,24 @@ pub struct ListingDelisted {
 #[event]
 pub struct ListingEnded {
     pub listing_pubkey: Pubkey,
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub winner: Pubkey,
+    pub winning_bid: u64,
+    pub amount_to_treasury: u64,
+    pub amount_to_seller: u64,
+    pub outcome: ListingEndOutcome,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct ListingPurchased {
+    pub listing_pubkey: Pubkey,
+    pub buyer: Pubkey,
+    pub buyout_price: u64,
+    pub amount_to_treasury: u64,
+    pub amount_to_seller: u64,
     #[index]
     pub label: String,
 }
@@ -34,6 +54,7 @@ pub struct BidPlaced {
     pub listing: Pubkey,
     pub current_bid: u64,
     pub end_time_in_slots: u64,
+    pub happy_hour: bool,
     #[index]
     pub label: String,
 }
@@ -44,3 +65,57 @@ pub struct UserCreated {
     #[index]
     pub label: String,
 }
+
+#[event]
+pub struct UserSelfExcluded {
+    pub user_pubkey: Pubkey,
+    pub excluded_from: i64,
+    pub excluded_until: i64,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub marketplace: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct ListingSettled {
+    pub listing_pubkey: Pubkey,
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub unpaid_winner: Pubkey,
+    pub unpaid_bid: u64,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub marketplace: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct MarketplacePaused {
+    pub marketplace: Pubkey,
+    pub paused: PauseFlags,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct MarketplaceUpdated {
+    pub marketplace: Pubkey,
+    pub update: MarketplaceUpdate,
+    #[index]
+    pub label: String,
+}
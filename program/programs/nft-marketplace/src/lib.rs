@@ -10,7 +10,10 @@ pub mod state;
 pub mod transfer;
 pub mod utils;
 
-use state::{MintCostTier, MintTier};
+use state::{
+    BidLimits, ExclusionPeriod, HappyHour, ListParams, ListingBounds, MintCostTier, MintTier,
+    PauseFlags,
+};
 
 pub use contexts::*;
 
@@ -37,22 +40,10 @@ pub mod nft_marketplace {
     pub fn list<'info>(
         ctx: Context<'_, '_, '_, 'info, List<'info>>,
         seed: u64,
-        bid_increment: u64,
-        timer_extension_in_slots: u64,
-        start_time_in_slots: u64,
-        initial_duration_in_slots: u64,
-        buyout_price: u64,
+        params: ListParams,
         amount: u64,
     ) -> Result<()> {
-        ctx.accounts.create_listing(
-            seed,
-            bid_increment,
-            timer_extension_in_slots,
-            start_time_in_slots,
-            initial_duration_in_slots,
-            buyout_price,
-            &ctx.bumps,
-        )?;
+        ctx.accounts.create_listing(seed, params, &ctx.bumps)?;
 
         ctx.accounts
             .transfer_to_escrow(amount, ctx.remaining_accounts)?;
@@ -67,15 +58,27 @@ pub mod nft_marketplace {
         ctx.accounts.initialize_user(&ctx.bumps)
     }
 
-    /* NOTE: currently disabled!
-    pub fn delist(ctx: Context<Delist>) -> Result<()> {
-        ctx.accounts.withdraw_and_close()
+    pub fn migrate_user_account(ctx: Context<MigrateUserAccount>) -> Result<()> {
+        ctx.accounts.migrate_user_account()
+    }
+
+    pub fn migrate_marketplace(ctx: Context<MigrateMarketplace>) -> Result<()> {
+        ctx.accounts.migrate_marketplace()
+    }
+
+    pub fn delist<'info>(
+        ctx: Context<'_, '_, '_, 'info, Delist<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.delist(amount, ctx.remaining_accounts)
     }
 
-    pub fn purchase(ctx: Context<Purchase>) -> Result<()> {
-        ctx.accounts.purchase()
+    pub fn purchase<'info>(
+        ctx: Context<'_, '_, '_, 'info, Purchase<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.purchase(amount, ctx.remaining_accounts)
     }
-    */
 
     pub fn place_bid(
         ctx: Context<PlaceBid>,
@@ -98,6 +101,13 @@ pub mod nft_marketplace {
         Ok(())
     }
 
+    pub fn settle_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleListing<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.settle_listing(amount, ctx.remaining_accounts)
+    }
+
     pub fn mint_bid_token(ctx: Context<MintBidToken>, tier: MintCostTier) -> Result<()> {
         ctx.accounts.mint_token(tier)?;
         ctx.accounts.reward_user()?;
@@ -113,4 +123,105 @@ pub mod nft_marketplace {
 
         Ok(())
     }
+
+    pub fn update_marketplace_happy_hour(
+        ctx: Context<UpdateMarketplace>,
+        happy_hour: HappyHour,
+    ) -> Result<()> {
+        ctx.accounts.update_happy_hour(happy_hour)
+    }
+
+    pub fn update_marketplace_settlement_grace(
+        ctx: Context<UpdateMarketplace>,
+        grace_in_slots: u64,
+    ) -> Result<()> {
+        ctx.accounts.update_settlement_grace(grace_in_slots)
+    }
+
+    pub fn update_marketplace_bid_cost_bounds(
+        ctx: Context<UpdateMarketplace>,
+        min_bid_cost: u64,
+        max_bid_cost: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .update_bid_cost_bounds(min_bid_cost, max_bid_cost)
+    }
+
+    pub fn update_marketplace_listing_bounds(
+        ctx: Context<UpdateMarketplace>,
+        bounds: ListingBounds,
+    ) -> Result<()> {
+        ctx.accounts.update_listing_bounds(bounds)
+    }
+
+    pub fn update_marketplace_fee(ctx: Context<UpdateMarketplace>, fee: u16) -> Result<()> {
+        ctx.accounts.update_fee(fee)
+    }
+
+    pub fn update_marketplace_token_metadata(
+        ctx: Context<UpdateMarketplaceTokenMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        ctx.accounts.update_token_metadata(name, symbol, uri)
+    }
+
+    pub fn update_marketplace_mint_gate(
+        ctx: Context<UpdateMarketplace>,
+        mint_requires_admin: bool,
+    ) -> Result<()> {
+        ctx.accounts.update_mint_gate(mint_requires_admin)
+    }
+
+    pub fn set_pause(ctx: Context<UpdateMarketplace>, paused: PauseFlags) -> Result<()> {
+        ctx.accounts.set_pause(paused)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.propose_admin(new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        ctx.accounts.accept_admin()
+    }
+
+    pub fn set_bid_limits(ctx: Context<SetBidLimits>, limits: BidLimits) -> Result<()> {
+        ctx.accounts.set_bid_limits(limits)
+    }
+
+    pub fn self_exclude(ctx: Context<SelfExclude>, period: ExclusionPeriod) -> Result<()> {
+        ctx.accounts.self_exclude(period)
+    }
+
+    pub fn create_bid_agent(
+        ctx: Context<CreateBidAgent>,
+        max_price: u64,
+        max_bids: u32,
+        bid_window_in_slots: u64,
+        deposit: u64,
+    ) -> Result<()> {
+        ctx.accounts.create_bid_agent(
+            max_price,
+            max_bids,
+            bid_window_in_slots,
+            deposit,
+            &ctx.bumps,
+        )
+    }
+
+    pub fn crank_bid_agent(ctx: Context<CrankBidAgent>) -> Result<()> {
+        ctx.accounts.crank_bid_agent()?;
+        ctx.accounts.reward_user()?;
+
+        Ok(())
+    }
+
+    pub fn cancel_bid_agent(ctx: Context<CancelBidAgent>) -> Result<()> {
+        ctx.accounts.cancel_bid_agent()
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_treasury(amount)
+    }
 }
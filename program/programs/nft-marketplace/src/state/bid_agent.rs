@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct BidAgent {
+    pub owner: Pubkey,
+    pub listing: Pubkey,
+    /// Marketplace of the listing, the listing is closed once the auction ends
+    pub marketplace: Pubkey,
+    /// The agent stops once the listing's current bid would exceed this
+    pub max_price: u64,
+    pub max_bids: u32,
+    pub bids_placed: u32,
+    /// Only bid during the last N slots of the auction, 0 means any time
+    pub bid_window_in_slots: u64,
+    /// sBid base units left to spend on bids, burned from the owner on deposit
+    pub deposited: u64,
+    pub bump: u8,
+
+    pub padding: [u8; 7],
+    pub _reserved: [u8; 32],
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BID_HISTORY_LENGTH;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, Default, InitSpace)]
+pub struct BidRecord {
+    pub bidder: Pubkey,
+    pub bid: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BidHistory {
+    pub listing: Pubkey,
+    /// Index where the next bid will be written
+    pub head: u8,
+    /// Number of valid records, capped at `BID_HISTORY_LENGTH`
+    pub count: u8,
+    pub bump: u8,
+
+    pub padding: [u8; 5],
+    pub bids: [BidRecord; BID_HISTORY_LENGTH],
+}
+
+impl BidHistory {
+    /// Appends a bid, overwriting the oldest record once the buffer is full.
+    pub fn push(&mut self, record: BidRecord) {
+        self.bids[self.head as usize] = record;
+        self.head = ((self.head as usize + 1) % BID_HISTORY_LENGTH) as u8;
+
+        if (self.count as usize) < BID_HISTORY_LENGTH {
+            self.count += 1;
+        }
+    }
+}
@@ -1,5 +1,70 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{MS_IN_SEC, MS_PER_SLOT, SBID_DECIMALS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum ListingEndOutcome {
+    Sold,
+    ReserveNotMet,
+}
+
+/// How a bid extends the auction end time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
+pub enum TimerMode {
+    /// Every bid adds the extension to the end time
+    Cumulative,
+    /// Every bid resets the end time to at least `now + extension`
+    ResetToAtLeast,
+    /// Cumulative, but never past `max_end_time_in_slots`
+    Capped,
+}
+
+/// Clock the listing's start, end and extension times are measured against
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
+pub enum TimingMode {
+    /// Times are slots, compared against `Clock::slot`
+    Slots,
+    /// Times are seconds, compared against `Clock::unix_timestamp`
+    UnixTimestamp,
+}
+
+impl TimingMode {
+    /// Current time in this timing mode.
+    pub fn current_time(&self, clock: &Clock) -> u64 {
+        match self {
+            TimingMode::Slots => clock.slot,
+            TimingMode::UnixTimestamp => clock.unix_timestamp.max(0) as u64,
+        }
+    }
+
+    /// Converts a marketplace-wide duration in slots into this timing mode.
+    pub fn duration_from_slots(&self, duration_in_slots: u64) -> Result<u64> {
+        match self {
+            TimingMode::Slots => Ok(duration_in_slots),
+            TimingMode::UnixTimestamp => Ok(duration_in_slots
+                .checked_mul(MS_PER_SLOT)
+                .ok_or(ProgramError::ArithmeticOverflow)?
+                / MS_IN_SEC as u64),
+        }
+    }
+}
+
+/// Parameters a listing is created with, see `ListingV2` for their meaning
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub struct ListParams {
+    pub bid_cost: u64,
+    pub bid_increment: u64,
+    pub timer_extension_in_slots: u64,
+    pub start_time_in_slots: u64,
+    pub initial_duration_in_slots: u64,
+    pub timer_mode: TimerMode,
+    pub timing_mode: TimingMode,
+    pub max_end_time_in_slots: u64,
+    pub buyout_price: u64,
+    pub reserve_price: u64,
+    pub sol_escrow: bool,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Listing {
@@ -22,6 +87,7 @@ pub struct Listing {
 pub struct ListingV2 {
     pub mint: Pubkey,
     pub seller: Pubkey,
+    /// sBid base units burned per bid
     pub bid_cost: u64,
     pub bid_increment: u64,
     pub current_bid: u64,
@@ -33,7 +99,64 @@ pub struct ListingV2 {
     pub buyout_price: u64,
     pub seed: u64,
     pub bump: u8,
+    /// When set, bids lock SOL in the listing's bid vault
+    pub sol_escrow: bool,
+    pub bid_vault_bump: u8,
+    pub timer_mode: TimerMode,
+    /// Unit of the `*_in_slots` times, they hold unix seconds in `UnixTimestamp` mode
+    pub timing_mode: TimingMode,
+    /// Layout version, see `LISTING_VERSION`
+    pub version: u8,
+
+    pub padding: [u8; 1],
+    /// Lowest winning bid, the NFT goes back to the seller below it
+    pub reserve_price: u64,
+    pub max_end_time_in_slots: u64,
+    /// Marketplace bidding pause total already credited to the end time
+    pub pause_credit_snapshot: u64,
+    pub _reserved: [u8; 8],
+}
+
+impl ListingV2 {
+    /// sBid base units burned per bid, version 0 listings hold `bid_cost` in whole sBid.
+    pub fn bid_cost_amount(&self) -> Result<u64> {
+        if self.version > 0 {
+            return Ok(self.bid_cost);
+        }
+
+        Ok(self
+            .bid_cost
+            .checked_mul(10u64.pow(SBID_DECIMALS as u32))
+            .ok_or(ProgramError::ArithmeticOverflow)?)
+    }
+
+    /// Current time in the listing's timing mode.
+    pub fn current_time(&self, clock: &Clock) -> u64 {
+        self.timing_mode.current_time(clock)
+    }
+
+    /// Converts a marketplace-wide duration in slots into the listing's timing mode.
+    pub fn duration_from_slots(&self, duration_in_slots: u64) -> Result<u64> {
+        self.timing_mode.duration_from_slots(duration_in_slots)
+    }
+
+    /// Extends the end time after a bid according to the listing's timer mode,
+    /// `now` is in the listing's timing mode.
+    pub fn extend_end_time(&mut self, extension_in_slots: u64, now: u64) -> Result<()> {
+        let extended = self
+            .end_time_in_slots
+            .checked_add(extension_in_slots)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        self.end_time_in_slots = match self.timer_mode {
+            TimerMode::Cumulative => extended,
+            TimerMode::ResetToAtLeast => self.end_time_in_slots.max(
+                now.checked_add(extension_in_slots)
+                    .ok_or(ProgramError::ArithmeticOverflow)?,
+            ),
+            TimerMode::Capped => extended.min(self.max_end_time_in_slots),
+        };
 
-    pub padding: [u8; 6],
-    pub _reserved: [u8; 32],
+        Ok(())
+    }
 }
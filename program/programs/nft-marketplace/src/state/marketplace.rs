@@ -1,5 +1,14 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    constants::{
+        DEFAULT_MAX_BID_COST, DEFAULT_MAX_BID_INCREMENT, DEFAULT_MAX_DURATION_IN_SLOTS,
+        DEFAULT_MAX_TIMER_EXTENSION_IN_SLOTS, DEFAULT_MIN_BID_COST, DEFAULT_MIN_BID_INCREMENT,
+        DEFAULT_MIN_TIMER_EXTENSION_IN_SLOTS, HAPPY_HOUR_WINDOWS, SECONDS_IN_DAY,
+    },
+    state::TimingMode,
+};
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
 pub enum MintCostTier {
     Tier1,
@@ -7,6 +16,107 @@ pub enum MintCostTier {
     Tier3,
 }
 
+/// Bounds for the parameters a listing is created with, durations are in slots
+/// and converted for listings timed in unix seconds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
+pub struct ListingBounds {
+    pub min_bid_increment: u64,
+    pub max_bid_increment: u64,
+    pub min_timer_extension_in_slots: u64,
+    pub max_timer_extension_in_slots: u64,
+    pub max_duration_in_slots: u64,
+}
+
+impl Default for ListingBounds {
+    fn default() -> Self {
+        Self {
+            min_bid_increment: DEFAULT_MIN_BID_INCREMENT,
+            max_bid_increment: DEFAULT_MAX_BID_INCREMENT,
+            min_timer_extension_in_slots: DEFAULT_MIN_TIMER_EXTENSION_IN_SLOTS,
+            max_timer_extension_in_slots: DEFAULT_MAX_TIMER_EXTENSION_IN_SLOTS,
+            max_duration_in_slots: DEFAULT_MAX_DURATION_IN_SLOTS,
+        }
+    }
+}
+
+/// Operations halted by the admin during an incident
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, Default, InitSpace)]
+pub struct PauseFlags {
+    pub listing: bool,
+    pub bidding: bool,
+    pub minting: bool,
+    pub settlement: bool,
+}
+
+/// A point in time or a duration, in both listing timing modes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, Default, InitSpace)]
+pub struct PauseTime {
+    pub slots: u64,
+    pub seconds: u64,
+}
+
+impl PauseTime {
+    pub fn now(clock: &Clock) -> Self {
+        Self {
+            slots: TimingMode::Slots.current_time(clock),
+            seconds: TimingMode::UnixTimestamp.current_time(clock),
+        }
+    }
+
+    pub fn get(&self, timing_mode: TimingMode) -> u64 {
+        match timing_mode {
+            TimingMode::Slots => self.slots,
+            TimingMode::UnixTimestamp => self.seconds,
+        }
+    }
+}
+
+/// Bidding pauses, auctions running during a pause get its length credited
+/// to their end time the next time they are used.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, Default, InitSpace)]
+pub struct BiddingPause {
+    /// Start of the ongoing pause, or of the last one once bidding resumed
+    pub started_at: PauseTime,
+    pub last_duration: PauseTime,
+    pub total_duration: PauseTime,
+}
+
+/// Token-2022 metadata of the sBid mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct SbidMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+/// Bounds for a listing's bid_cost, in sBid base units
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub struct BidCostBounds {
+    pub min_bid_cost: u64,
+    pub max_bid_cost: u64,
+}
+
+/// An admin change to the marketplace, with the values before and after it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum MarketplaceUpdate {
+    Fee {
+        old: u16,
+        new: u16,
+    },
+    TokenMetadata {
+        old: SbidMetadata,
+        new: SbidMetadata,
+    },
+    ListingBounds {
+        old: ListingBounds,
+        new: ListingBounds,
+    },
+    BidCostBounds {
+        old: BidCostBounds,
+        new: BidCostBounds,
+    },
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
 pub struct MintTier {
     pub tier: MintCostTier,
@@ -15,6 +125,43 @@ pub struct MintTier {
     pub bonus: u64,
 }
 
+/// A daily UTC window in seconds since midnight, it may wrap around midnight.
+/// A window with `start == end` is unused.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, Default, InitSpace)]
+pub struct HappyHourWindow {
+    pub start_second_of_day: u32,
+    pub end_second_of_day: u32,
+}
+
+impl HappyHourWindow {
+    pub fn contains(&self, second_of_day: u32) -> bool {
+        if self.start_second_of_day <= self.end_second_of_day {
+            second_of_day >= self.start_second_of_day && second_of_day < self.end_second_of_day
+        } else {
+            second_of_day >= self.start_second_of_day || second_of_day < self.end_second_of_day
+        }
+    }
+}
+
+/// Multipliers are in basis points and applied to the listing's
+/// `bid_increment` and `timer_extension_in_slots` during happy hour.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, Default, InitSpace)]
+pub struct HappyHour {
+    pub windows: [HappyHourWindow; HAPPY_HOUR_WINDOWS],
+    pub increment_multiplier_bps: u16,
+    pub extension_multiplier_bps: u16,
+}
+
+impl HappyHour {
+    pub fn is_active(&self, unix_timestamp: i64) -> bool {
+        let second_of_day = unix_timestamp.rem_euclid(SECONDS_IN_DAY) as u32;
+
+        self.windows
+            .iter()
+            .any(|window| window.contains(second_of_day))
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Marketplace {
@@ -27,7 +174,40 @@ pub struct Marketplace {
     pub mint_tiers: [MintTier; 3],
     pub bump: u8,
     pub treasury_bump: u8,
+    /// When set, sBid purchases must be co-signed by the admin
+    pub mint_requires_admin: bool,
+
+    pub padding: [u8; 1],
+    pub happy_hour: HappyHour,
+    /// Slots after the auction end during which the winner can still pay
+    pub settlement_grace_in_slots: u64,
+    /// Bounds for a listing's bid_cost, in sBid base units, see `bid_cost_bounds`
+    pub min_bid_cost: u64,
+    pub max_bid_cost: u64,
+    pub listing_bounds: ListingBounds,
+    /// Admin at initialization, only used to derive the marketplace address
+    pub creator: Pubkey,
+    /// Admin proposed by the current admin, the default pubkey when none
+    pub pending_admin: Pubkey,
+    pub paused: PauseFlags,
+    pub bidding_pause: BiddingPause,
+    pub _reserved: [u8; 12],
+}
+
+impl Marketplace {
+    /// Bid cost bounds, the defaults for marketplaces created before they were
+    /// configurable and still holding zeroes.
+    pub fn bid_cost_bounds(&self) -> BidCostBounds {
+        if self.max_bid_cost == 0 {
+            return BidCostBounds {
+                min_bid_cost: DEFAULT_MIN_BID_COST,
+                max_bid_cost: DEFAULT_MAX_BID_COST,
+            };
+        }
 
-    pub padding: [u8; 2],
-    pub _reserved: [u8; 64],
+        BidCostBounds {
+            min_bid_cost: self.min_bid_cost,
+            max_bid_cost: self.max_bid_cost,
+        }
+    }
 }
@@ -1,7 +1,11 @@
+pub mod bid_agent;
+pub mod bid_history;
 pub mod listing;
 pub mod marketplace;
 pub mod user;
 
+pub use bid_agent::*;
+pub use bid_history::*;
 pub use listing::*;
 pub use marketplace::*;
 pub use user::*;
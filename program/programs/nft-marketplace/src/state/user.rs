@@ -1,5 +1,61 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{SECONDS_IN_DAY, SECONDS_IN_MONTH, SECONDS_IN_WEEK};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
+pub enum LimitPeriod {
+    Daily,
+    Weekly,
+}
+
+impl LimitPeriod {
+    pub fn duration_in_secs(&self) -> i64 {
+        match self {
+            LimitPeriod::Daily => SECONDS_IN_DAY,
+            LimitPeriod::Weekly => SECONDS_IN_WEEK,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
+pub enum ExclusionPeriod {
+    Day,
+    Week,
+    Month,
+}
+
+impl ExclusionPeriod {
+    pub fn duration_in_secs(&self) -> i64 {
+        match self {
+            ExclusionPeriod::Day => SECONDS_IN_DAY,
+            ExclusionPeriod::Week => SECONDS_IN_WEEK,
+            ExclusionPeriod::Month => SECONDS_IN_MONTH,
+        }
+    }
+}
+
+/// Responsible gaming limits, a value of 0 means no limit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
+pub struct BidLimits {
+    pub period: LimitPeriod,
+    pub max_bids: u32,
+    pub max_spent: u64,
+}
+
+impl BidLimits {
+    /// Whether `limits` are at least as strict as these in every dimension,
+    /// a longer period is stricter as usage is counted over more time.
+    pub fn is_tightened_by(&self, limits: &BidLimits) -> bool {
+        let tighter = |current: u64, new: u64| {
+            new == current || (new != 0 && (current == 0 || new < current))
+        };
+
+        limits.period.duration_in_secs() >= self.period.duration_in_secs()
+            && tighter(self.max_bids as u64, limits.max_bids as u64)
+            && tighter(self.max_spent, limits.max_spent)
+    }
+}
+
 #[account]
 #[derive(InitSpace, Debug)]
 pub struct UserAccount {
@@ -12,6 +68,37 @@ pub struct UserAccount {
     pub points: u32,
     pub bump: u8,
 
-    pub padding: [u8; 3],
-    pub _reserved: [u8; 32],
+    // Zeroed in accounts created before the fields below, which are grown to
+    // this layout by `migrate_user_account`
+    pub bid_limits: BidLimits,
+    /// Start of the fixed limit window, a new one starts with the first bid or
+    /// mint once `bid_limits.period` has elapsed
+    pub limit_window_start: i64,
+    pub bids_in_window: u32,
+    pub spent_in_window: u64,
+    /// Looser limits requested by the user, applied when the current window ends
+    pub pending_bid_limits: Option<BidLimits>,
+
+    pub excluded_until: i64,
+
+    pub total_auctions_unpaid: u32,
+
+    pub _reserved: [u8; 2],
+}
+
+impl UserAccount {
+    /// Starts a new limit window once the current one has elapsed.
+    pub fn roll_limit_window(&mut self, now: i64) {
+        let elapsed = now.saturating_sub(self.limit_window_start);
+
+        if elapsed >= self.bid_limits.period.duration_in_secs() {
+            if let Some(pending_bid_limits) = self.pending_bid_limits.take() {
+                self.bid_limits = pending_bid_limits;
+            }
+
+            self.limit_window_start = now;
+            self.bids_in_window = 0;
+            self.spent_in_window = 0;
+        }
+    }
 }
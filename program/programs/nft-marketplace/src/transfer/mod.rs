@@ -17,6 +17,7 @@ pub fn transfer_asset<'info>(
     to: &AccountInfo<'info>,
     authority_from: &AccountInfo<'info>,
     authority_to: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
     mint: &InterfaceAccount<'info, Mint>,
     metadata: &AccountInfo<'info>,
     token_program: &Program<'info, Token>,
@@ -39,6 +40,7 @@ pub fn transfer_asset<'info>(
             let to = to.clone();
             let auth_from = authority_from.clone();
             let auth_to = authority_to.clone();
+            let payer = payer.clone();
             let mint = &mint.to_account_info().clone();
 
             let edition = next_account_info(&mut remaining_accounts_iter).ok();
@@ -66,7 +68,7 @@ pub fn transfer_asset<'info>(
                 .token_record(owner_tr)
                 .destination_token_record(destination_tr)
                 .authority(&auth_from)
-                .payer(&auth_to)
+                .payer(&payer)
                 .system_program(&system_program)
                 .sysvar_instructions(&sysvar_instructions)
                 .spl_token_program(&token_program)
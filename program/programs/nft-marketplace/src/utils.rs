@@ -1,9 +1,60 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
 
 use solana_program::{program::invoke, system_instruction};
 
 pub use crate::errors::MarketplaceErrorCode;
-use crate::state::{ListingV2, MintCostTier, MintTier};
+use crate::{
+    constants::{BID_PLACED_LABEL, BPS_DENOMINATOR, MAX_MINT_TIER_BONUS_BPS, SECONDS_IN_DAY},
+    events::BidPlaced,
+    state::{
+        BidHistory, BidRecord, BiddingPause, HappyHour, ListingV2, Marketplace, MintCostTier,
+        MintTier, TimerMode, TimingMode, UserAccount,
+    },
+};
+
+/// Grows an account created with an older, shorter layout of `T` to `space`.
+/// Fields added since either took over zeroed padding / reserved bytes or were
+/// appended, so the zero filled account deserializes with them all zeroed.
+pub fn realloc_legacy_account<'info, T: Discriminator>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(
+        account.try_borrow_data()?.get(..8) == Some(&T::DISCRIMINATOR[..]),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    if account.data_len() >= space {
+        return Ok(());
+    }
+
+    let rent = (Rent::get()?)
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+
+    if rent > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent,
+        )?;
+    }
+
+    account.realloc(space, true)?;
+
+    Ok(())
+}
 
 pub fn assert_correct_highest_bidder_and_bid(
     listing: &Account<ListingV2>,
@@ -30,14 +81,24 @@ pub fn assert_already_highest_bidder(
     Ok(())
 }
 
+/// Bids are accepted on `[start, end)`, the end slot belongs to settlement.
+pub fn is_bidding_open(start_time: u64, end_time: u64, now: u64) -> bool {
+    now >= start_time && now < end_time
+}
+
+/// Settlement is allowed from the end slot on, so it never overlaps with bidding.
+pub fn is_auction_over(end_time: u64, now: u64) -> bool {
+    now >= end_time
+}
+
 pub fn assert_auction_active(listing: &Account<ListingV2>) -> Result<()> {
-    let current_slot = Clock::get()?.slot;
+    let now = listing.current_time(&Clock::get()?);
 
     if !listing.is_active {
         return err!(MarketplaceErrorCode::AuctionNotActive);
-    } else if current_slot < listing.start_time_in_slots {
+    } else if now < listing.start_time_in_slots {
         return err!(MarketplaceErrorCode::AuctionNotStarted);
-    } else if current_slot > listing.end_time_in_slots {
+    } else if !is_bidding_open(listing.start_time_in_slots, listing.end_time_in_slots, now) {
         return err!(MarketplaceErrorCode::AuctionEnded);
     }
 
@@ -45,15 +106,65 @@ pub fn assert_auction_active(listing: &Account<ListingV2>) -> Result<()> {
 }
 
 pub fn assert_auction_ended(listing: &Account<ListingV2>) -> Result<()> {
-    let current_slot = Clock::get()?.slot;
+    let now = listing.current_time(&Clock::get()?);
 
-    if current_slot < listing.end_time_in_slots {
+    if !is_auction_over(listing.end_time_in_slots, now) {
         return err!(MarketplaceErrorCode::AuctionNotEnded);
     }
 
     Ok(())
 }
 
+fn get_settlement_deadline(listing: &ListingV2, grace_in_slots: u64) -> Result<u64> {
+    listing
+        .end_time_in_slots
+        .checked_add(listing.duration_from_slots(grace_in_slots)?)
+        .ok_or(ProgramError::ArithmeticOverflow.into())
+}
+
+pub fn assert_within_settlement_grace(
+    listing: &Account<ListingV2>,
+    grace_in_slots: u64,
+) -> Result<()> {
+    let now = listing.current_time(&Clock::get()?);
+
+    if now > get_settlement_deadline(listing, grace_in_slots)? {
+        return err!(MarketplaceErrorCode::SettlementGracePeriodExpired);
+    }
+
+    Ok(())
+}
+
+pub fn assert_settlement_grace_expired(
+    listing: &Account<ListingV2>,
+    grace_in_slots: u64,
+) -> Result<()> {
+    let now = listing.current_time(&Clock::get()?);
+
+    if now <= get_settlement_deadline(listing, grace_in_slots)? {
+        return err!(MarketplaceErrorCode::SettlementGracePeriodActive);
+    }
+
+    Ok(())
+}
+
+/// Bids reaching the buyout price take it off the table, the auction has to run its course.
+pub fn assert_buyout_available(listing: &ListingV2) -> Result<()> {
+    if listing.buyout_price == 0 || listing.current_bid >= listing.buyout_price {
+        return err!(MarketplaceErrorCode::BuyoutNotAvailable);
+    } else if !listing.is_active {
+        return err!(MarketplaceErrorCode::AuctionNotActive);
+    }
+
+    let now = listing.current_time(&Clock::get()?);
+
+    if is_auction_over(listing.end_time_in_slots, now) {
+        return err!(MarketplaceErrorCode::AuctionEnded);
+    }
+
+    Ok(())
+}
+
 pub fn assert_allowed_claimer(listing: &Account<ListingV2>, bidder: &AccountInfo) -> Result<()> {
     if listing.highest_bidder.key() == Pubkey::default() {
         if listing.seller.key() != bidder.key() {
@@ -66,6 +177,10 @@ pub fn assert_allowed_claimer(listing: &Account<ListingV2>, bidder: &AccountInfo
     Ok(())
 }
 
+pub fn is_reserve_met(listing: &Account<ListingV2>) -> bool {
+    listing.current_bid >= listing.reserve_price
+}
+
 pub fn assert_auction_delist_eligible(listing: &Account<ListingV2>) -> Result<()> {
     if listing.highest_bidder.key() != Pubkey::default() {
         return err!(MarketplaceErrorCode::CannotDelistWithActiveBidder);
@@ -78,6 +193,52 @@ pub fn assert_auction_delist_eligible(listing: &Account<ListingV2>) -> Result<()
     Ok(())
 }
 
+pub fn assert_not_self_excluded(user_account: &Account<UserAccount>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    if now < user_account.excluded_until {
+        return err!(MarketplaceErrorCode::UserSelfExcluded);
+    }
+
+    Ok(())
+}
+
+pub fn track_bid_limit(user_account: &mut Account<UserAccount>) -> Result<()> {
+    user_account.roll_limit_window(Clock::get()?.unix_timestamp);
+
+    let max_bids = user_account.bid_limits.max_bids;
+    require!(
+        max_bids == 0 || user_account.bids_in_window < max_bids,
+        MarketplaceErrorCode::BidLimitReached
+    );
+
+    user_account.bids_in_window = user_account
+        .bids_in_window
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+pub fn track_spend_limit(user_account: &mut Account<UserAccount>, amount: u64) -> Result<()> {
+    user_account.roll_limit_window(Clock::get()?.unix_timestamp);
+
+    let spent_in_window = user_account
+        .spent_in_window
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let max_spent = user_account.bid_limits.max_spent;
+    require!(
+        max_spent == 0 || spent_in_window <= max_spent,
+        MarketplaceErrorCode::SpendLimitReached
+    );
+
+    user_account.spent_in_window = spent_in_window;
+
+    Ok(())
+}
+
 pub fn assert_valid_mint_tier_costs(tier: MintCostTier) -> Result<()> {
     match tier {
         MintCostTier::Tier1 | MintCostTier::Tier2 | MintCostTier::Tier3 => Ok(()),
@@ -85,11 +246,267 @@ pub fn assert_valid_mint_tier_costs(tier: MintCostTier) -> Result<()> {
     }
 }
 
-pub fn get_mint_tier(tiers: &[MintTier], tier: MintCostTier) -> &MintTier {
+/// Every `MintCostTier` must be priced exactly once with a non-zero cost and
+/// amount, and a bonus of at most `MAX_MINT_TIER_BONUS_BPS` of the amount.
+pub fn assert_valid_mint_tiers(tiers: &[MintTier]) -> Result<()> {
+    for tier in [
+        MintCostTier::Tier1,
+        MintCostTier::Tier2,
+        MintCostTier::Tier3,
+    ] {
+        require!(
+            tiers
+                .iter()
+                .filter(|mint_tier| mint_tier.tier == tier)
+                .count()
+                == 1,
+            MarketplaceErrorCode::InvalidMintTiers
+        );
+    }
+
+    for mint_tier in tiers {
+        require!(
+            mint_tier.amount > 0 && mint_tier.cost > 0,
+            MarketplaceErrorCode::InvalidMintTierAmount
+        );
+
+        let max_bonus =
+            (mint_tier.amount as u128 * MAX_MINT_TIER_BONUS_BPS as u128) / BPS_DENOMINATOR as u128;
+        require!(
+            mint_tier.bonus as u128 <= max_bonus,
+            MarketplaceErrorCode::InvalidMintTierBonus
+        );
+    }
+
+    Ok(())
+}
+
+pub fn get_mint_tier(tiers: &[MintTier], tier: MintCostTier) -> Result<&MintTier> {
     tiers
         .iter()
         .find(|mint_tier| mint_tier.tier == tier)
-        .expect("Tier must exist")
+        .ok_or(MarketplaceErrorCode::InvalidMintTiers.into())
+}
+
+/// Splits `price` into the marketplace fee (`fee` is in basis points) and the
+/// remainder that goes to the seller.
+pub fn split_marketplace_fee(price: u64, fee: u16) -> Result<(u64, u64)> {
+    let amount_to_treasury = price
+        .checked_mul(fee as u64)
+        .ok_or(ProgramError::ArithmeticOverflow)?
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let amount_to_seller = price
+        .checked_sub(amount_to_treasury)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok((amount_to_treasury, amount_to_seller))
+}
+
+pub fn apply_bps(value: u64, bps: u16) -> Result<u64> {
+    let result = value
+        .checked_mul(bps as u64)
+        .ok_or(ProgramError::ArithmeticOverflow)?
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(result)
+}
+
+/// Scales the bid increment during happy hour, rounding up so that a bid
+/// always raises the price by at least 1 lamport.
+pub fn apply_bps_to_bid_increment(bid_increment: u64, bps: u16) -> Result<u64> {
+    let result = bid_increment
+        .checked_mul(bps as u64)
+        .ok_or(ProgramError::ArithmeticOverflow)?
+        .div_ceil(BPS_DENOMINATOR);
+
+    Ok(result.max(1))
+}
+
+pub fn assert_valid_happy_hour(happy_hour: &HappyHour) -> Result<()> {
+    let seconds_in_day = SECONDS_IN_DAY as u32;
+
+    for window in happy_hour.windows.iter() {
+        require!(
+            window.start_second_of_day < seconds_in_day
+                && window.end_second_of_day < seconds_in_day,
+            MarketplaceErrorCode::InvalidHappyHour
+        );
+    }
+
+    // Happy hour means cheaper increments and longer timer extensions
+    require!(
+        happy_hour.increment_multiplier_bps > 0
+            && happy_hour.increment_multiplier_bps as u64 <= BPS_DENOMINATOR
+            && happy_hour.extension_multiplier_bps as u64 >= BPS_DENOMINATOR,
+        MarketplaceErrorCode::InvalidHappyHour
+    );
+
+    Ok(())
+}
+
+pub fn assert_valid_bid_cost(marketplace: &Account<Marketplace>, bid_cost: u64) -> Result<()> {
+    let bounds = marketplace.bid_cost_bounds();
+
+    if bid_cost < bounds.min_bid_cost || bid_cost > bounds.max_bid_cost {
+        return err!(MarketplaceErrorCode::InvalidBidCost);
+    }
+
+    Ok(())
+}
+
+/// Credits the bidding pauses completed since the listing was last used to its
+/// end time. Earlier pauses are credited in full, the last one only if the
+/// auction was still running when it started, so ended auctions never reopen.
+pub fn credit_paused_time(listing: &mut ListingV2, bidding_pause: &BiddingPause) -> Result<()> {
+    let timing_mode = listing.timing_mode;
+    let total = bidding_pause.total_duration.get(timing_mode);
+    let credit = total.saturating_sub(listing.pause_credit_snapshot);
+
+    if credit == 0 {
+        return Ok(());
+    }
+
+    let last_pause = bidding_pause.last_duration.get(timing_mode).min(credit);
+    let mut extension = credit - last_pause;
+
+    let end_time = listing
+        .end_time_in_slots
+        .checked_add(extension)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if end_time >= bidding_pause.started_at.get(timing_mode) {
+        extension += last_pause;
+    }
+
+    listing.end_time_in_slots = listing
+        .end_time_in_slots
+        .checked_add(extension)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if listing.timer_mode == TimerMode::Capped {
+        listing.max_end_time_in_slots = listing
+            .max_end_time_in_slots
+            .checked_add(extension)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+    listing.pause_credit_snapshot = total;
+
+    Ok(())
+}
+
+/// An auction running when bidding got paused will be extended on resume,
+/// so it can't be ended until then.
+pub fn assert_not_awaiting_pause_credit(
+    listing: &ListingV2,
+    marketplace: &Marketplace,
+) -> Result<()> {
+    let pause_start = marketplace
+        .bidding_pause
+        .started_at
+        .get(listing.timing_mode);
+
+    if marketplace.paused.bidding && listing.end_time_in_slots >= pause_start {
+        return err!(MarketplaceErrorCode::BiddingPaused);
+    }
+
+    Ok(())
+}
+
+/// Checks the listing parameters against the marketplace bounds, durations
+/// are compared in the listing's timing mode.
+pub fn assert_valid_listing_params(
+    marketplace: &Account<Marketplace>,
+    timing_mode: TimingMode,
+    bid_increment: u64,
+    timer_extension: u64,
+    start_time: u64,
+    initial_duration: u64,
+    buyout_price: u64,
+) -> Result<()> {
+    let bounds = &marketplace.listing_bounds;
+
+    if bid_increment < bounds.min_bid_increment || bid_increment > bounds.max_bid_increment {
+        return err!(MarketplaceErrorCode::InvalidBidIncrement);
+    } else if timer_extension
+        < timing_mode.duration_from_slots(bounds.min_timer_extension_in_slots)?
+        || timer_extension > timing_mode.duration_from_slots(bounds.max_timer_extension_in_slots)?
+    {
+        return err!(MarketplaceErrorCode::InvalidTimerExtension);
+    } else if initial_duration == 0
+        || initial_duration > timing_mode.duration_from_slots(bounds.max_duration_in_slots)?
+    {
+        return err!(MarketplaceErrorCode::InvalidListingDuration);
+    } else if start_time < timing_mode.current_time(&Clock::get()?) {
+        return err!(MarketplaceErrorCode::InvalidListingStartTime);
+    } else if buyout_price != 0 && buyout_price <= bid_increment {
+        return err!(MarketplaceErrorCode::InvalidBuyoutPrice);
+    }
+
+    Ok(())
+}
+
+/// Makes `bidder` the highest bidder, bumping the price and the end time
+/// (scaled during happy hour) and recording the bid in the listing history.
+/// Listings created before bid histories (version 0) have none, every later
+/// listing must pass its history so bids are recorded and its rent returned.
+pub fn assert_bid_history_provided<T>(listing: &ListingV2, bid_history: &Option<T>) -> Result<()> {
+    require!(
+        bid_history.is_some() || listing.version == 0,
+        MarketplaceErrorCode::MissingBidHistory
+    );
+
+    Ok(())
+}
+
+pub fn apply_bid(
+    listing: &mut Account<ListingV2>,
+    bid_history: Option<&mut Box<Account<BidHistory>>>,
+    happy_hour: &HappyHour,
+    bidder: &Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let is_happy_hour = happy_hour.is_active(clock.unix_timestamp);
+
+    let (bid_increment, timer_extension_in_slots) = if is_happy_hour {
+        (
+            apply_bps_to_bid_increment(listing.bid_increment, happy_hour.increment_multiplier_bps)?,
+            apply_bps(
+                listing.timer_extension_in_slots,
+                happy_hour.extension_multiplier_bps,
+            )?,
+        )
+    } else {
+        (listing.bid_increment, listing.timer_extension_in_slots)
+    };
+
+    listing.current_bid = listing
+        .current_bid
+        .checked_add(bid_increment)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    listing.highest_bidder = *bidder;
+    let now = listing.current_time(&clock);
+    listing.extend_end_time(timer_extension_in_slots, now)?;
+
+    if let Some(bid_history) = bid_history {
+        bid_history.push(BidRecord {
+            bidder: *bidder,
+            bid: listing.current_bid,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(BidPlaced {
+        bidder: *bidder,
+        listing: listing.key(),
+        current_bid: listing.current_bid,
+        end_time_in_slots: listing.end_time_in_slots,
+        happy_hour: is_happy_hour,
+        label: BID_PLACED_LABEL.to_string(),
+    });
+
+    Ok(())
 }
 
 pub fn transfer_sol<'a>(
@@ -106,3 +523,352 @@ pub fn transfer_sol<'a>(
 
     Ok(())
 }
+
+pub fn transfer_sol_from_bid_vault<'a>(
+    listing: &Account<ListingV2>,
+    bid_vault: AccountInfo<'a>,
+    to: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    amount: u64,
+) -> Result<()> {
+    let listing_key = listing.key();
+    let bump = [listing.bid_vault_bump];
+    let signer_seeds: [&[&[u8]]; 1] = [&[b"bid_vault", listing_key.as_ref(), &bump][..]];
+
+    let transfer_accounts = Transfer {
+        from: bid_vault,
+        to,
+    };
+
+    let cpi_context = CpiContext::new_with_signer(system_program, transfer_accounts, &signer_seeds);
+
+    transfer(cpi_context, amount)
+}
+
+/// Bid vault of a `sol_escrow` listing. Listings without escrow don't need it,
+/// and those created before it can't pass it as their vault bump was never set.
+pub fn escrow_bid_vault<'info>(
+    bid_vault: &Option<SystemAccount<'info>>,
+) -> Result<AccountInfo<'info>> {
+    Ok(bid_vault
+        .as_ref()
+        .ok_or(MarketplaceErrorCode::MissingBidVault)?
+        .to_account_info())
+}
+
+/// Sweeps the bid vault into the seller, returning the rent funded on `list`
+pub fn close_bid_vault<'a>(
+    listing: &Account<ListingV2>,
+    bid_vault: &Option<SystemAccount<'a>>,
+    seller: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+) -> Result<()> {
+    if !listing.sol_escrow {
+        return Ok(());
+    }
+
+    let bid_vault = escrow_bid_vault(bid_vault)?;
+    let lamports = bid_vault.lamports();
+    if lamports == 0 {
+        return Ok(());
+    }
+
+    transfer_sol_from_bid_vault(listing, bid_vault, seller, system_program, lamports)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{
+        constants::{LISTING_VERSION, MS_IN_SEC, MS_PER_SLOT},
+        state::{BidLimits, LimitPeriod, PauseTime},
+    };
+
+    fn listing(
+        end_time: u64,
+        timer_mode: TimerMode,
+        timing_mode: TimingMode,
+        max_end_time: u64,
+    ) -> ListingV2 {
+        ListingV2 {
+            mint: Pubkey::default(),
+            seller: Pubkey::default(),
+            bid_cost: 0,
+            bid_increment: 0,
+            current_bid: 0,
+            highest_bidder: Pubkey::default(),
+            timer_extension_in_slots: 0,
+            start_time_in_slots: 0,
+            end_time_in_slots: end_time,
+            is_active: true,
+            buyout_price: 0,
+            seed: 0,
+            bump: 0,
+            sol_escrow: false,
+            bid_vault_bump: 0,
+            timer_mode,
+            timing_mode,
+            version: LISTING_VERSION,
+            padding: [0; 1],
+            reserve_price: 0,
+            max_end_time_in_slots: max_end_time,
+            pause_credit_snapshot: 0,
+            _reserved: [0; 8],
+        }
+    }
+
+    fn bidding_pause(started_at: u64, last_duration: u64, total_duration: u64) -> BiddingPause {
+        let time = |slots| PauseTime { slots, seconds: 0 };
+
+        BiddingPause {
+            started_at: time(started_at),
+            last_duration: time(last_duration),
+            total_duration: time(total_duration),
+        }
+    }
+
+    #[test]
+    fn running_auction_is_credited_the_pause_once() {
+        let mut listing = listing(100, TimerMode::Cumulative, TimingMode::Slots, 0);
+        let pause = bidding_pause(90, 20, 20);
+
+        credit_paused_time(&mut listing, &pause).unwrap();
+        credit_paused_time(&mut listing, &pause).unwrap();
+
+        assert_eq!(listing.end_time_in_slots, 120);
+        assert_eq!(listing.pause_credit_snapshot, 20);
+    }
+
+    #[test]
+    fn ended_auction_is_not_credited_the_pause() {
+        let mut listing = listing(100, TimerMode::Capped, TimingMode::Slots, 100);
+        let pause = bidding_pause(150, 20, 20);
+
+        credit_paused_time(&mut listing, &pause).unwrap();
+
+        assert_eq!(listing.end_time_in_slots, 100);
+        assert_eq!(listing.max_end_time_in_slots, 100);
+    }
+
+    fn mint_tiers() -> [MintTier; 3] {
+        let tier = |tier, amount| MintTier {
+            tier,
+            amount,
+            cost: 1,
+            bonus: amount / 10,
+        };
+
+        [
+            tier(MintCostTier::Tier1, 75),
+            tier(MintCostTier::Tier2, 200),
+            tier(MintCostTier::Tier3, 500),
+        ]
+    }
+
+    #[test]
+    fn mint_tiers_must_be_priced_once() {
+        assert!(assert_valid_mint_tiers(&mint_tiers()).is_ok());
+
+        let mut tiers = mint_tiers();
+        tiers[2].tier = MintCostTier::Tier1;
+        assert_eq!(
+            assert_valid_mint_tiers(&tiers),
+            Err(MarketplaceErrorCode::InvalidMintTiers.into())
+        );
+        assert_eq!(
+            get_mint_tier(&tiers, MintCostTier::Tier3).err(),
+            Some(MarketplaceErrorCode::InvalidMintTiers.into())
+        );
+
+        let mut tiers = mint_tiers();
+        tiers[1].cost = 0;
+        assert_eq!(
+            assert_valid_mint_tiers(&tiers),
+            Err(MarketplaceErrorCode::InvalidMintTierAmount.into())
+        );
+
+        let mut tiers = mint_tiers();
+        tiers[0].bonus = tiers[0].amount;
+        assert_eq!(
+            assert_valid_mint_tiers(&tiers),
+            Err(MarketplaceErrorCode::InvalidMintTierBonus.into())
+        );
+    }
+
+    #[test]
+    fn looser_bid_limits_wait_for_the_window_to_end() {
+        let limits = |period, max_bids, max_spent| BidLimits {
+            period,
+            max_bids,
+            max_spent,
+        };
+        let current = limits(LimitPeriod::Daily, 5, 100);
+
+        assert!(current.is_tightened_by(&current));
+        assert!(current.is_tightened_by(&limits(LimitPeriod::Weekly, 4, 100)));
+        assert!(!current.is_tightened_by(&limits(LimitPeriod::Daily, 0, 100)));
+        assert!(!current.is_tightened_by(&limits(LimitPeriod::Daily, 5, 101)));
+        assert!(!limits(LimitPeriod::Weekly, 0, 0).is_tightened_by(&current));
+        assert!(limits(LimitPeriod::Daily, 0, 0).is_tightened_by(&current));
+
+        let mut user = UserAccount {
+            owner: Pubkey::default(),
+            total_bids_placed: 0,
+            total_auctions_participated: 0,
+            total_auctions_won: 0,
+            total_auctions_created: 0,
+            points: 0,
+            bump: 0,
+            bid_limits: current,
+            limit_window_start: 0,
+            bids_in_window: 5,
+            spent_in_window: 100,
+            pending_bid_limits: Some(limits(LimitPeriod::Daily, 0, 0)),
+            excluded_until: 0,
+            total_auctions_unpaid: 0,
+            _reserved: [0; 2],
+        };
+
+        user.roll_limit_window(SECONDS_IN_DAY - 1);
+        assert_eq!(user.bid_limits, current);
+        assert_eq!(user.bids_in_window, 5);
+
+        user.roll_limit_window(SECONDS_IN_DAY);
+        assert_eq!(user.bid_limits, limits(LimitPeriod::Daily, 0, 0));
+        assert_eq!(user.pending_bid_limits, None);
+        assert_eq!(user.bids_in_window, 0);
+    }
+
+    #[test]
+    fn legacy_listings_burn_whole_sbid_per_bid() {
+        let mut listing = listing(0, TimerMode::Cumulative, TimingMode::Slots, 0);
+        listing.bid_cost = 1;
+        assert_eq!(listing.bid_cost_amount().unwrap(), 1);
+
+        listing.version = 0;
+        assert_eq!(listing.bid_cost_amount().unwrap(), 1_000_000);
+
+        listing.bid_cost = u64::MAX;
+        assert!(listing.bid_cost_amount().is_err());
+    }
+
+    #[test]
+    fn only_legacy_listings_may_omit_the_bid_history() {
+        let mut listing = listing(0, TimerMode::Cumulative, TimingMode::Slots, 0);
+        assert!(assert_bid_history_provided(&listing, &Some(())).is_ok());
+        assert!(assert_bid_history_provided(&listing, &None::<()>).is_err());
+
+        listing.version = 0;
+        assert!(assert_bid_history_provided(&listing, &None::<()>).is_ok());
+    }
+
+    #[test]
+    fn bids_reaching_the_buyout_price_close_the_buyout() {
+        let mut listing = listing(0, TimerMode::Cumulative, TimingMode::Slots, 0);
+        listing.buyout_price = 100;
+        listing.current_bid = 100;
+        assert_eq!(
+            assert_buyout_available(&listing),
+            Err(MarketplaceErrorCode::BuyoutNotAvailable.into())
+        );
+
+        listing.current_bid = 150;
+        assert_eq!(
+            assert_buyout_available(&listing),
+            Err(MarketplaceErrorCode::BuyoutNotAvailable.into())
+        );
+
+        // below the buyout price it is only the listing state that is left to check
+        listing.current_bid = 99;
+        listing.is_active = false;
+        assert_eq!(
+            assert_buyout_available(&listing),
+            Err(MarketplaceErrorCode::AuctionNotActive.into())
+        );
+    }
+
+    #[test]
+    fn end_slot_belongs_to_settlement() {
+        assert!(is_bidding_open(10, 20, 19));
+        assert!(!is_bidding_open(10, 20, 20));
+        assert!(is_auction_over(20, 20));
+        assert!(!is_auction_over(20, 19));
+    }
+
+    proptest! {
+        #[test]
+        fn bidding_and_settlement_never_overlap(start in any::<u64>(), end in any::<u64>(), now in any::<u64>()) {
+            prop_assert!(!(is_bidding_open(start, end, now) && is_auction_over(end, now)));
+        }
+
+        #[test]
+        fn every_started_slot_is_bidding_or_settlement(start in 0..u64::MAX / 2, duration in 0..u64::MAX / 2, now in any::<u64>()) {
+            let end = start + duration;
+
+            prop_assume!(now >= start);
+            prop_assert!(is_bidding_open(start, end, now) ^ is_auction_over(end, now));
+        }
+
+        #[test]
+        fn settlement_deadline_is_never_before_the_end(end in any::<u64>(), grace in any::<u64>(), unix_timestamp in any::<bool>()) {
+            let timing_mode = if unix_timestamp { TimingMode::UnixTimestamp } else { TimingMode::Slots };
+            let listing = listing(end, TimerMode::Cumulative, timing_mode, 0);
+
+            if let Ok(deadline) = get_settlement_deadline(&listing, grace) {
+                prop_assert!(deadline >= end);
+            }
+        }
+
+        #[test]
+        fn grace_converts_from_slots_to_seconds(grace in 0..u64::MAX / MS_PER_SLOT) {
+            let slots = listing(0, TimerMode::Cumulative, TimingMode::Slots, 0);
+            let seconds = listing(0, TimerMode::Cumulative, TimingMode::UnixTimestamp, 0);
+
+            prop_assert_eq!(slots.duration_from_slots(grace).unwrap(), grace);
+            prop_assert_eq!(
+                seconds.duration_from_slots(grace).unwrap(),
+                grace * MS_PER_SLOT / MS_IN_SEC as u64
+            );
+        }
+
+        #[test]
+        fn happy_hour_bids_always_raise_the_price(bid_increment in 1..u64::MAX / 10_000, bps in 1..=10_000u16) {
+            let scaled = apply_bps_to_bid_increment(bid_increment, bps).unwrap();
+
+            prop_assert!(scaled >= 1);
+            prop_assert!(scaled <= bid_increment);
+            prop_assert!(scaled >= apply_bps(bid_increment, bps).unwrap());
+        }
+
+        #[test]
+        fn pause_credit_never_reopens_an_ended_auction(end in 0..u64::MAX / 4, gap in 1..u64::MAX / 4, earlier in 0..u64::MAX / 4, last in 0..u64::MAX / 4) {
+            // earlier pauses ended before the last one started, after the auction end
+            let started_at = end + earlier + gap;
+            let mut listing = listing(end, TimerMode::Cumulative, TimingMode::Slots, 0);
+
+            credit_paused_time(&mut listing, &bidding_pause(started_at, last, earlier + last)).unwrap();
+
+            prop_assert!(listing.end_time_in_slots < started_at);
+        }
+
+        #[test]
+        fn bids_never_shorten_the_auction(end in 0..u64::MAX / 4, extension in 0..u64::MAX / 4, now in 0..u64::MAX / 4, max_offset in 0..u64::MAX / 4) {
+            for timer_mode in [TimerMode::Cumulative, TimerMode::ResetToAtLeast, TimerMode::Capped] {
+                let mut listing = listing(end, timer_mode, TimingMode::Slots, end + max_offset);
+
+                listing.extend_end_time(extension, now).unwrap();
+
+                prop_assert!(listing.end_time_in_slots >= end);
+                prop_assert!(listing.end_time_in_slots <= end.max(now) + extension);
+                if timer_mode == TimerMode::Capped {
+                    prop_assert!(listing.end_time_in_slots <= end + max_offset);
+                }
+                if timer_mode == TimerMode::ResetToAtLeast {
+                    prop_assert!(listing.end_time_in_slots >= now + extension);
+                }
+            }
+        }
+    }
+}
@@ -10,6 +10,8 @@ import {
   Pda,
   AccountMeta,
   TransactionBuilder,
+  none,
+  some,
 } from "@metaplex-foundation/umi";
 import { NftMarketplace } from "../target/types/nft_marketplace";
 import { createAndMintNftForCollection } from "./utils/nft";
@@ -29,6 +31,34 @@ import {
   placeBid,
   updateMarketplaceMintTiers,
   initializeUser,
+  migrateUserAccount,
+  migrateMarketplace,
+  withdrawTreasury,
+  purchase,
+  delist,
+  fetchBidHistory,
+  setBidLimits,
+  LimitPeriod,
+  selfExclude,
+  ExclusionPeriod,
+  updateMarketplaceHappyHour,
+  updateMarketplaceMintGate,
+  updateMarketplaceSettlementGrace,
+  updateMarketplaceBidCostBounds,
+  updateMarketplaceListingBounds,
+  updateMarketplaceFee,
+  updateMarketplaceTokenMetadata,
+  proposeAdmin,
+  setPause,
+  acceptAdmin,
+  TimerMode,
+  TimingMode,
+  settleListing,
+  HappyHour,
+  createBidAgent,
+  crankBidAgent,
+  cancelBidAgent,
+  fetchBidAgent,
 } from "../clients/generated/umi/src/";
 import {
   fetchToken,
@@ -44,7 +74,10 @@ import {
 
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccount,
+  createMint,
   getAssociatedTokenAddressSync,
+  getTokenMetadata,
   TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
@@ -58,6 +91,8 @@ import {
   fetchUserAccountPDA,
   generateRandomU64Seed,
   parseAnchorError,
+  getListingStartSlot,
+  waitForSlot,
 } from "./utils/utils";
 import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
 import {
@@ -104,6 +139,7 @@ describe("nft-marketplace", () => {
   let user1 = anchor.web3.Keypair.generate();
   let user2 = anchor.web3.Keypair.generate();
   let user3 = anchor.web3.Keypair.generate();
+  let user4 = anchor.web3.Keypair.generate();
 
   const marketplaceName = "Penny Auctions Marketplace";
   let marketplacePDA: Pda;
@@ -113,9 +149,25 @@ describe("nft-marketplace", () => {
 
   let seed = generateRandomU64Seed();
   let seedPnftListing = generateRandomU64Seed();
+  let seedBuyNowListing = generateRandomU64Seed();
+  let seedDelistListing = generateRandomU64Seed();
+  let seedBidAgentListing = generateRandomU64Seed();
+  let seedEscrowListing = generateRandomU64Seed();
+  let seedReserveListing = generateRandomU64Seed();
+  let seedPremiumListing = generateRandomU64Seed();
+  let seedResetTimerListing = generateRandomU64Seed();
+  let seedCappedTimerListing = generateRandomU64Seed();
+  let seedTimestampListing = generateRandomU64Seed();
+  let seedPausedListing = generateRandomU64Seed();
 
   let nft: Nft;
   let pNft: Nft;
+  let buyNowNft: Nft;
+  let delistNft: Nft;
+  let bidAgentNft: Nft;
+  let escrowNft: Nft;
+  let reserveNft: Nft;
+  let timestampNft: Nft;
 
   let programId: UmiPublicKey;
 
@@ -123,7 +175,7 @@ describe("nft-marketplace", () => {
 
   before(async () => {
     await Promise.all(
-      [initializer, user1, user2, user3].map(async (k) => {
+      [initializer, user1, user2, user3, user4].map(async (k) => {
         return await provider.connection.requestAirdrop(
           k.publicKey,
           10 * anchor.web3.LAMPORTS_PER_SOL
@@ -154,8 +206,50 @@ describe("nft-marketplace", () => {
         true
       );
 
+      const {
+        mint: bMint,
+        ata: bAta,
+        collection: bCollection,
+      } = await createAndMintNftForCollection(umi, 3, user1.publicKey);
+
+      const {
+        mint: dMint,
+        ata: dAta,
+        collection: dCollection,
+      } = await createAndMintNftForCollection(umi, 4, user2.publicKey);
+
+      const {
+        mint: aMint,
+        ata: aAta,
+        collection: aCollection,
+      } = await createAndMintNftForCollection(umi, 5, user1.publicKey);
+
+      const {
+        mint: eMint,
+        ata: eAta,
+        collection: eCollection,
+      } = await createAndMintNftForCollection(umi, 6, user1.publicKey);
+
+      const {
+        mint: rMint,
+        ata: rAta,
+        collection: rCollection,
+      } = await createAndMintNftForCollection(umi, 7, user1.publicKey);
+
+      const {
+        mint: tMint,
+        ata: tAta,
+        collection: tCollection,
+      } = await createAndMintNftForCollection(umi, 8, user1.publicKey);
+
       nft = { mint, ata, collection };
       pNft = { mint: pMint, ata: pAta, collection: pCollection };
+      buyNowNft = { mint: bMint, ata: bAta, collection: bCollection };
+      delistNft = { mint: dMint, ata: dAta, collection: dCollection };
+      bidAgentNft = { mint: aMint, ata: aAta, collection: aCollection };
+      escrowNft = { mint: eMint, ata: eAta, collection: eCollection };
+      reserveNft = { mint: rMint, ata: rAta, collection: rCollection };
+      timestampNft = { mint: tMint, ata: tAta, collection: tCollection };
 
       admin = createSignerFromKeypair(
         umi,
@@ -260,9 +354,56 @@ describe("nft-marketplace", () => {
     }
   });
 
+  it("Update Mint Tiers with invalid tiers - should fail!", async () => {
+    const [tier1, tier2, tier3] = MINT_TIER_COSTS;
+    const invalidTiers: Array<[Array<MintTier>, number, string]> = [
+      [[tier1, tier2, tier2], 6049, "InvalidMintTiers"],
+      [
+        [tier1, tier2, { ...tier3, cost: BigInt(0) }],
+        6050,
+        "InvalidMintTierAmount",
+      ],
+      [
+        [tier1, { ...tier2, amount: BigInt(0) }, tier3],
+        6050,
+        "InvalidMintTierAmount",
+      ],
+      [
+        [{ ...tier1, bonus: tier1.amount }, tier2, tier3],
+        6051,
+        "InvalidMintTierBonus",
+      ],
+    ];
+
+    for (const [tiers, expectedNumber, expectedCode] of invalidTiers) {
+      try {
+        await updateMarketplaceMintTiers(umi, {
+          admin,
+          marketplace: fromWeb3JsPublicKey(marketplace),
+          tiers,
+        }).sendAndConfirm(umi, options);
+
+        expect.fail("❌ Update Mint Tiers tx should have failed!");
+      } catch (err) {
+        const { errorNumber, errorCode } = parseAnchorError(
+          err.transactionLogs
+        );
+
+        expect(errorNumber).to.eq(expectedNumber);
+        expect(errorCode).to.eq(expectedCode);
+      }
+    }
+
+    const marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.mintTiers).deep.equal(MINT_TIER_COSTS);
+  });
+
   it("Initialize user accounts", async () => {
     await Promise.all(
-      [initializer, user1, user2, user3].map(async (u) => {
+      [initializer, user1, user2, user3, user4].map(async (u) => {
         try {
           const userSigner = createSignerFromKeypair(
             umi,
@@ -299,6 +440,82 @@ describe("nft-marketplace", () => {
     );
   });
 
+  it("Migrating an up to date user account is a no-op", async () => {
+    const userSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+    );
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      userSigner.publicKey
+    );
+    const accountOld = await provider.connection.getAccountInfo(
+      toWeb3JsPublicKey(userAccount)
+    );
+
+    try {
+      await migrateUserAccount(umi, {
+        user: userSigner,
+        userAccount,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Migrate User Account tx failed!");
+    }
+
+    const accountNew = await provider.connection.getAccountInfo(
+      toWeb3JsPublicKey(userAccount)
+    );
+    expect(accountNew.data.equals(accountOld.data)).to.be.true;
+    expect(accountNew.lamports).to.eq(accountOld.lamports);
+  });
+
+  it("Migrating an up to date marketplace is a no-op", async () => {
+    const accountOld = await provider.connection.getAccountInfo(marketplace);
+
+    try {
+      await migrateMarketplace(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Migrate Marketplace tx failed!");
+    }
+
+    const accountNew = await provider.connection.getAccountInfo(marketplace);
+    expect(accountNew.data.equals(accountOld.data)).to.be.true;
+    expect(accountNew.lamports).to.eq(accountOld.lamports);
+
+    // the marketplace stays derived from the admin that created it
+    const marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.creator).to.eq(admin.publicKey);
+
+    // only the admin can migrate the marketplace
+    try {
+      await migrateMarketplace(umi, {
+        admin: createSignerFromKeypair(
+          umi,
+          umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+        ),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Migrate Marketplace tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(2001);
+      expect(errorCode).to.eq("ConstraintHasOne");
+    }
+  });
+
   it("Create Listing", async () => {
     const price = 3 * anchor.web3.LAMPORTS_PER_SOL;
 
@@ -317,12 +534,12 @@ describe("nft-marketplace", () => {
     );
 
     const escrow = getAssociatedTokenAddressSync(mint, listing, true);
-    const currentSlot = await provider.connection.getSlot();
+    const startSlot = await getListingStartSlot(provider.connection);
 
     let listingConfig = {
       bidIncrement: BigInt(price / 1000),
       timerExtension: BigInt(12), // 12 slots ~ 5 seconds
-      startTimestamp: BigInt(currentSlot),
+      startTimestamp: startSlot,
       initialDuration: BigInt(24), // 24 slots ~ 10 seconds
       buyoutPrice: BigInt(price),
       amount: BigInt(1),
@@ -365,13 +582,22 @@ describe("nft-marketplace", () => {
           anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
         ),
         seed: BigInt(listingConfig.seed.toString()),
-        bidIncrement: listingConfig.bidIncrement,
-        timerExtensionInSlots: listingConfig.timerExtension,
-        startTimeInSlots: listingConfig.startTimestamp,
-        initialDurationInSlots: listingConfig.initialDuration,
-        buyoutPrice: listingConfig.buyoutPrice,
+        params: {
+          bidCost: BigInt(10 ** 6),
+          bidIncrement: listingConfig.bidIncrement,
+          timerExtensionInSlots: listingConfig.timerExtension,
+          startTimeInSlots: listingConfig.startTimestamp,
+          initialDurationInSlots: listingConfig.initialDuration,
+          timerMode: TimerMode.Cumulative,
+          timingMode: TimingMode.Slots,
+          maxEndTimeInSlots: BigInt(0),
+          buyoutPrice: listingConfig.buyoutPrice,
+          reservePrice: BigInt(0),
+          solEscrow: false,
+        },
         amount: listingConfig.amount,
       }).sendAndConfirm(umi, options);
+      await waitForSlot(provider.connection, startSlot);
 
       const user = await fetchUserAccount(umi, userAccount);
 
@@ -388,7 +614,8 @@ describe("nft-marketplace", () => {
       expect(listingAccount.seller.toString()).to.equal(
         user1.publicKey.toString()
       );
-      expect(listingAccount.bidCost).to.eq(BigInt(1));
+      expect(listingAccount.bidCost).to.eq(BigInt(10 ** 6));
+      expect(listingAccount.version).to.eq(1);
       expect(listingAccount.bidIncrement).deep.eq(listingConfig.bidIncrement);
       expect(listingAccount.currentBid).eq(BigInt(0));
       expect(listingAccount.highestBidder.toString()).to.deep.eq(
@@ -397,9 +624,9 @@ describe("nft-marketplace", () => {
       expect(listingAccount.timerExtensionInSlots).deep.eq(
         listingConfig.timerExtension
       );
-      expect(listingAccount.startTimeInSlots).deep.eq(BigInt(currentSlot));
+      expect(listingAccount.startTimeInSlots).deep.eq(startSlot);
       expect(listingAccount.endTimeInSlots).deep.eq(
-        BigInt(currentSlot) + BigInt(listingConfig.initialDuration)
+        startSlot + BigInt(listingConfig.initialDuration)
       );
       expect(listingAccount.isActive).to.eq(true);
       expect(listingAccount.buyoutPrice).deep.eq(listingConfig.buyoutPrice);
@@ -722,6 +949,136 @@ describe("nft-marketplace", () => {
     }
   });
 
+  it("User 3 place a bid with an invalid sBid token account - should fail!", async () => {
+    const mint = nft.mint;
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seed.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+
+    const userSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user3.secretKey)
+    );
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      userSigner.publicKey
+    );
+
+    // another user's sBid account
+    const [user2Ata] = PublicKey.findProgramAddressSync(
+      [
+        user2.publicKey.toBytes(),
+        TOKEN_2022_PROGRAM_ID.toBytes(),
+        sBidMint.publicKey.toBytes(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    // a Token-2022 account of another mint
+    const otherMint = await createMint(
+      provider.connection,
+      user3,
+      user3.publicKey,
+      null,
+      6,
+      undefined,
+      confirmOpts,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const otherMintAta = await createAssociatedTokenAccount(
+      provider.connection,
+      user3,
+      otherMint,
+      user3.publicKey,
+      confirmOpts,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    // a legacy token account, with the legacy token program
+    const legacyMint = await createMint(
+      provider.connection,
+      user3,
+      user3.publicKey,
+      null,
+      6,
+      undefined,
+      confirmOpts,
+      TOKEN_PROGRAM_ID
+    );
+    const legacyAta = await createAssociatedTokenAccount(
+      provider.connection,
+      user3,
+      legacyMint,
+      user3.publicKey,
+      confirmOpts,
+      TOKEN_PROGRAM_ID
+    );
+
+    const cases = [
+      {
+        bidderSbidAta: user2Ata,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        expectedNumber: 2015,
+        expectedCode: "ConstraintTokenOwner",
+      },
+      {
+        bidderSbidAta: otherMintAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        expectedNumber: 2014,
+        expectedCode: "ConstraintTokenMint",
+      },
+      {
+        bidderSbidAta: legacyAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        expectedNumber: 2022,
+        expectedCode: "ConstraintMintTokenProgram",
+      },
+    ];
+
+    for (const {
+      bidderSbidAta,
+      tokenProgram,
+      expectedNumber,
+      expectedCode,
+    } of cases) {
+      try {
+        await placeBid(umi, {
+          bidder: userSigner,
+          sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+          userAccount,
+          bidderSbidAta: fromWeb3JsPublicKey(bidderSbidAta),
+          mint: fromWeb3JsPublicKey(mint),
+          listing: fromWeb3JsPublicKey(listing),
+          marketplace: fromWeb3JsPublicKey(marketplace),
+          tokenProgram: fromWeb3JsPublicKey(tokenProgram),
+          highestBidder: listingAccount.highestBidder,
+          currentBid: listingAccount.currentBid,
+        }).sendAndConfirm(umi, options);
+
+        expect.fail("❌ Place Bid tx should have failed!");
+      } catch (err) {
+        const { errorNumber, errorCode } = parseAnchorError(
+          err.transactionLogs
+        );
+
+        expect(errorNumber).to.eq(expectedNumber);
+        expect(errorCode).to.eq(expectedCode);
+      }
+    }
+  });
+
   it("User 3 place a bid", async () => {
     const mint = nft.mint;
     const [listing] = PublicKey.findProgramAddressSync(
@@ -811,6 +1168,93 @@ describe("nft-marketplace", () => {
     expect(user.owner).to.eq(userSigner.publicKey);
   });
 
+  it("Bid history records the placed bids", async () => {
+    const mint = nft.mint;
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seed.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [bidHistory] = PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("bid_history"), listing.toBuffer()],
+      program.programId
+    );
+
+    const listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+    const bidHistoryAccount = await fetchBidHistory(
+      umi,
+      fromWeb3JsPublicKey(bidHistory)
+    );
+
+    expect(bidHistoryAccount.listing.toString()).to.eq(listing.toString());
+    expect(bidHistoryAccount.count).to.eq(2);
+    expect(bidHistoryAccount.head).to.eq(2);
+
+    const [firstBid, secondBid] = bidHistoryAccount.bids;
+    expect(firstBid.bidder.toString()).to.eq(user2.publicKey.toString());
+    expect(firstBid.bid).to.eq(listingAccount.bidIncrement);
+    expect(secondBid.bidder.toString()).to.eq(user3.publicKey.toString());
+    expect(secondBid.bid).to.eq(listingAccount.currentBid);
+    expect(secondBid.slot >= firstBid.slot).to.eq(true);
+  });
+
+  it("User 1 delists a listing with bids - should fail!", async () => {
+    const mint = nft.mint;
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seed.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    const sellerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+    );
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+
+    try {
+      await delist(umi, {
+        seller: sellerSigner,
+        sellerAta: fromWeb3JsPublicKey(nft.ata),
+        mint: fromWeb3JsPublicKey(mint),
+        listing: fromWeb3JsPublicKey(listing),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Delist tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6009);
+      expect(errorCode).to.eq("CannotDelistWithActiveBidder");
+    }
+  });
+
   it("User 3 Ends Auction", async () => {
     const mint = nft.mint;
     const [listing] = PublicKey.findProgramAddressSync(
@@ -853,6 +1297,17 @@ describe("nft-marketplace", () => {
     const treasuryBalanceOld = await provider.connection.getBalance(
       toWeb3JsPublicKey(treasury)
     );
+    const sellerBalanceOld = await provider.connection.getBalance(
+      toWeb3JsPublicKey(listingAccountOld.seller)
+    );
+    const escrowRent = await provider.connection.getBalance(escrow);
+    const [bidHistory] = PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("bid_history"), listing.toBuffer()],
+      program.programId
+    );
+    const listingRent =
+      (await provider.connection.getBalance(listing)) +
+      (await provider.connection.getBalance(bidHistory));
 
     // We sleep 'till auction end time passes
     await new Promise((resolve) => setTimeout(resolve, 30 * 1000));
@@ -866,7 +1321,6 @@ describe("nft-marketplace", () => {
     try {
       await endListing(umi, {
         user: userSigner,
-        admin,
         seller: listingAccountOld.seller,
         userAccount,
         userAta: fromWeb3JsPublicKey(ata),
@@ -886,12 +1340,9 @@ describe("nft-marketplace", () => {
       console.log(err);
     }
 
-    const listingAccount = await fetchListingV2(
-      umi,
-      fromWeb3JsPublicKey(listing)
-    );
-
-    expect(listingAccount.isActive).to.eq(false);
+    // check listing and bid history have been closed
+    expect(await provider.connection.getAccountInfo(listing)).to.eq(null);
+    expect(await provider.connection.getAccountInfo(bidHistory)).to.eq(null);
 
     try {
       // check escrow ATA has been closed
@@ -908,12 +1359,28 @@ describe("nft-marketplace", () => {
     );
     expect(user1NftAtaBalance.value.amount).to.equal("1");
 
-    // check treasury has received the current bid amount
+    // check treasury has received the marketplace fee and the seller the rest
+    const marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    const amountToTreasury =
+      (listingAccountOld.currentBid * BigInt(marketplaceAccount.fee)) /
+      BigInt(10000);
+    const amountToSeller = listingAccountOld.currentBid - amountToTreasury;
+
     const treasuryBalanceNew = await provider.connection.getBalance(
       toWeb3JsPublicKey(treasury)
     );
     expect(treasuryBalanceNew).to.equal(
-      treasuryBalanceOld + Number(listingAccount.currentBid)
+      treasuryBalanceOld + Number(amountToTreasury)
+    );
+
+    const sellerBalanceNew = await provider.connection.getBalance(
+      toWeb3JsPublicKey(listingAccountOld.seller)
+    );
+    expect(sellerBalanceNew).to.equal(
+      sellerBalanceOld + Number(amountToSeller) + escrowRent + listingRent
     );
 
     const user = await fetchUserAccount(umi, userAccount);
@@ -939,14 +1406,14 @@ describe("nft-marketplace", () => {
     );
 
     const escrow = getAssociatedTokenAddressSync(mint, listing, true);
-    const currentSlot = await provider.connection.getSlot();
+    const startSlot = await getListingStartSlot(provider.connection);
     const timerExtensionInSlots = 12;
     const initialDurationInSlots = 24;
 
     let listingConfig = {
       bidIncrement: BigInt(price / 1000),
       timerExtension: BigInt(12), // 12 slots ~ 5 seconds
-      startTimestamp: BigInt(currentSlot),
+      startTimestamp: startSlot,
       initialDuration: BigInt(24), // 24 slots ~ 10 seconds
       buyoutPrice: BigInt(price),
       amount: BigInt(1),
@@ -1020,16 +1487,25 @@ describe("nft-marketplace", () => {
               anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
             ),
             seed: BigInt(listingConfig.seed.toString()),
-            bidIncrement: listingConfig.bidIncrement,
-            timerExtensionInSlots: listingConfig.timerExtension,
-            startTimeInSlots: listingConfig.startTimestamp,
-            initialDurationInSlots: listingConfig.initialDuration,
-            buyoutPrice: listingConfig.buyoutPrice,
+            params: {
+              bidCost: BigInt(10 ** 6),
+              bidIncrement: listingConfig.bidIncrement,
+              timerExtensionInSlots: listingConfig.timerExtension,
+              startTimeInSlots: listingConfig.startTimestamp,
+              initialDurationInSlots: listingConfig.initialDuration,
+              timerMode: TimerMode.Cumulative,
+              timingMode: TimingMode.Slots,
+              maxEndTimeInSlots: BigInt(0),
+              buyoutPrice: listingConfig.buyoutPrice,
+              reservePrice: BigInt(0),
+              solEscrow: false,
+            },
             amount: listingConfig.amount,
           }).addRemainingAccounts(remainingAccounts)
         )
         .add(setComputeUnitLimit(umi, { units: 600_000 }))
         .sendAndConfirm(umi, options);
+      await waitForSlot(provider.connection, startSlot);
     } catch (err) {
       console.log("err:", err);
     }
@@ -1049,7 +1525,7 @@ describe("nft-marketplace", () => {
     expect(listingAccount.seller.toString()).to.equal(
       initializer.publicKey.toString()
     );
-    expect(listingAccount.bidCost).to.eq(BigInt(1));
+    expect(listingAccount.bidCost).to.eq(BigInt(10 ** 6));
     expect(listingAccount.bidIncrement).deep.eq(listingConfig.bidIncrement);
     expect(listingAccount.currentBid).eq(BigInt(0));
     expect(listingAccount.highestBidder.toString()).to.deep.eq(
@@ -1058,9 +1534,9 @@ describe("nft-marketplace", () => {
     expect(listingAccount.timerExtensionInSlots).deep.eq(
       listingConfig.timerExtension
     );
-    expect(listingAccount.startTimeInSlots).deep.eq(BigInt(currentSlot));
+    expect(listingAccount.startTimeInSlots).deep.eq(startSlot);
     expect(listingAccount.endTimeInSlots).deep.eq(
-      BigInt(currentSlot) + BigInt(listingConfig.initialDuration)
+      startSlot + BigInt(listingConfig.initialDuration)
     );
     expect(listingAccount.isActive).to.eq(true);
     expect(listingAccount.buyoutPrice).deep.eq(listingConfig.buyoutPrice);
@@ -1211,6 +1687,17 @@ describe("nft-marketplace", () => {
     const treasuryBalanceOld = await provider.connection.getBalance(
       toWeb3JsPublicKey(treasury)
     );
+    const sellerBalanceOld = await provider.connection.getBalance(
+      toWeb3JsPublicKey(listingAccountOld.seller)
+    );
+    const escrowRent = await provider.connection.getBalance(escrow);
+    const [bidHistory] = PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("bid_history"), listing.toBuffer()],
+      program.programId
+    );
+    const listingRent =
+      (await provider.connection.getBalance(listing)) +
+      (await provider.connection.getBalance(bidHistory));
 
     await new Promise((resolve) => setTimeout(resolve, 20 * 1000));
 
@@ -1252,7 +1739,6 @@ describe("nft-marketplace", () => {
         .add(
           endListing(umi, {
             user: userSigner,
-            admin,
             seller: listingAccountOld.seller,
             userAccount,
             userAta: fromWeb3JsPublicKey(ata),
@@ -1275,12 +1761,9 @@ describe("nft-marketplace", () => {
       console.log(err);
     }
 
-    const listingAccount = await fetchListingV2(
-      umi,
-      fromWeb3JsPublicKey(listing)
-    );
-
-    expect(listingAccount.isActive).to.eq(false);
+    // check listing and bid history have been closed
+    expect(await provider.connection.getAccountInfo(listing)).to.eq(null);
+    expect(await provider.connection.getAccountInfo(bidHistory)).to.eq(null);
 
     try {
       // check escrow ATA has been closed
@@ -1297,18 +1780,2927 @@ describe("nft-marketplace", () => {
     );
     expect(user1NftAtaBalance.value.amount).to.equal("1");
 
-    // check treasury has received the current bid amount
+    // check treasury has received the marketplace fee and the seller the rest
+    const marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    const amountToTreasury =
+      (listingAccountOld.currentBid * BigInt(marketplaceAccount.fee)) /
+      BigInt(10000);
+    const amountToSeller = listingAccountOld.currentBid - amountToTreasury;
+
     const treasuryBalanceNew = await provider.connection.getBalance(
       toWeb3JsPublicKey(treasury)
     );
     expect(treasuryBalanceNew).to.equal(
-      treasuryBalanceOld + Number(listingAccount.currentBid)
+      treasuryBalanceOld + Number(amountToTreasury)
+    );
+
+    const sellerBalanceNew = await provider.connection.getBalance(
+      toWeb3JsPublicKey(listingAccountOld.seller)
+    );
+    expect(sellerBalanceNew).to.equal(
+      sellerBalanceOld + Number(amountToSeller) + escrowRent + listingRent
     );
 
     const user = await fetchUserAccount(umi, userAccount);
     expect(user.totalAuctionsWon).to.eq(1);
     expect(user.points).to.eq(53);
   });
+
+  it("Create Buy Now Listing", async () => {
+    const price = 2 * LAMPORTS_PER_SOL;
+    const mint = buyNowNft.mint;
+    const collection = buyNowNft.collection;
+    const ata = buyNowNft.ata;
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedBuyNowListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+    const startSlot = await getListingStartSlot(provider.connection);
+
+    const sellerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+    );
+
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      sellerSigner.publicKey
+    );
+
+    try {
+      await list(umi, {
+        seller: sellerSigner,
+        admin,
+        userAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(collection),
+        sellerAta: fromWeb3JsPublicKey(ata),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_PROGRAM_ID),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        seed: BigInt(seedBuyNowListing.toString()),
+        params: {
+          bidCost: BigInt(10 ** 6),
+          bidIncrement: BigInt(price / 1000),
+          timerExtensionInSlots: BigInt(12),
+          startTimeInSlots: startSlot,
+          initialDurationInSlots: BigInt(240),
+          timerMode: TimerMode.Cumulative,
+          timingMode: TimingMode.Slots,
+          maxEndTimeInSlots: BigInt(0),
+          buyoutPrice: BigInt(price),
+          reservePrice: BigInt(0),
+          solEscrow: false,
+        },
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+      await waitForSlot(provider.connection, startSlot);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Create Buy Now Listing tx failed!");
+    }
+
+    const listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+    expect(listingAccount.buyoutPrice).deep.eq(BigInt(price));
+    expect(listingAccount.isActive).to.eq(true);
+  });
+
+  it("User 3 purchases Buy Now Listing", async () => {
+    const mint = buyNowNft.mint;
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedBuyNowListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    const listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+
+    const buyerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user3.secretKey)
+    );
+
+    const ata = getAssociatedTokenAddressSync(mint, user3.publicKey);
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+
+    const [treasury] = umi.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([116, 114, 101, 97, 115, 117, 114, 121])
+      ),
+      publicKeySerializer().serialize(fromWeb3JsPublicKey(marketplace)),
+    ]);
+
+    const treasuryBalanceOld = await provider.connection.getBalance(
+      toWeb3JsPublicKey(treasury)
+    );
+    const sellerBalanceOld = await provider.connection.getBalance(
+      user1.publicKey
+    );
+    const escrowRent = await provider.connection.getBalance(escrow);
+    const [bidHistory] = PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("bid_history"), listing.toBuffer()],
+      program.programId
+    );
+    const listingRent =
+      (await provider.connection.getBalance(listing)) +
+      (await provider.connection.getBalance(bidHistory));
+
+    try {
+      await purchase(umi, {
+        buyer: buyerSigner,
+        seller: listingAccount.seller,
+        buyerAta: fromWeb3JsPublicKey(ata),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(buyNowNft.collection),
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Purchase tx failed!");
+    }
+
+    // check listing, bid history and escrow have been closed
+    expect(await provider.connection.getAccountInfo(listing)).to.eq(null);
+    expect(await provider.connection.getAccountInfo(bidHistory)).to.eq(null);
+    expect(await provider.connection.getAccountInfo(escrow)).to.eq(null);
+
+    // check user3 has received the NFT
+    const buyerNftAtaBalance = await provider.connection.getTokenAccountBalance(
+      ata
+    );
+    expect(buyerNftAtaBalance.value.amount).to.equal("1");
+
+    const marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    const amountToTreasury =
+      (listingAccount.buyoutPrice * BigInt(marketplaceAccount.fee)) /
+      BigInt(10000);
+    const amountToSeller = listingAccount.buyoutPrice - amountToTreasury;
+
+    const treasuryBalanceNew = await provider.connection.getBalance(
+      toWeb3JsPublicKey(treasury)
+    );
+    expect(treasuryBalanceNew).to.equal(
+      treasuryBalanceOld + Number(amountToTreasury)
+    );
+
+    const sellerBalanceNew = await provider.connection.getBalance(
+      user1.publicKey
+    );
+    expect(sellerBalanceNew).to.equal(
+      sellerBalanceOld + Number(amountToSeller) + escrowRent + listingRent
+    );
+  });
+
+  it("User 2 lists and delists an NFT without bids", async () => {
+    const mint = delistNft.mint;
+    const collection = delistNft.collection;
+    const ata = delistNft.ata;
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedDelistListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+    const startSlot = await getListingStartSlot(provider.connection);
+
+    const sellerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user2.secretKey)
+    );
+
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      sellerSigner.publicKey
+    );
+
+    try {
+      await list(umi, {
+        seller: sellerSigner,
+        admin,
+        userAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(collection),
+        sellerAta: fromWeb3JsPublicKey(ata),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_PROGRAM_ID),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        seed: BigInt(seedDelistListing.toString()),
+        params: {
+          bidCost: BigInt(10 ** 6),
+          bidIncrement: BigInt(LAMPORTS_PER_SOL / 1000),
+          timerExtensionInSlots: BigInt(12),
+          startTimeInSlots: startSlot,
+          initialDurationInSlots: BigInt(240),
+          timerMode: TimerMode.Cumulative,
+          timingMode: TimingMode.Slots,
+          maxEndTimeInSlots: BigInt(0),
+          buyoutPrice: BigInt(LAMPORTS_PER_SOL),
+          reservePrice: BigInt(0),
+          solEscrow: false,
+        },
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+      await waitForSlot(provider.connection, startSlot);
+
+      await delist(umi, {
+        seller: sellerSigner,
+        sellerAta: fromWeb3JsPublicKey(ata),
+        mint: fromWeb3JsPublicKey(mint),
+        listing: fromWeb3JsPublicKey(listing),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Delist tx failed!");
+    }
+
+    // check listing and escrow have been closed
+    expect(await provider.connection.getAccountInfo(listing)).to.eq(null);
+    expect(await provider.connection.getAccountInfo(escrow)).to.eq(null);
+
+    // check seller has received the NFT back
+    const sellerAta = await provider.connection.getTokenAccountBalance(ata);
+    expect(sellerAta.value.amount).to.equal("1");
+  });
+
+  it("Create Bid Agent Listing", async () => {
+    const price = 2 * LAMPORTS_PER_SOL;
+    const mint = bidAgentNft.mint;
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedBidAgentListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+    const startSlot = await getListingStartSlot(provider.connection);
+
+    const sellerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+    );
+
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      sellerSigner.publicKey
+    );
+
+    try {
+      await list(umi, {
+        seller: sellerSigner,
+        admin,
+        userAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(bidAgentNft.collection),
+        sellerAta: fromWeb3JsPublicKey(bidAgentNft.ata),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_PROGRAM_ID),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        seed: BigInt(seedBidAgentListing.toString()),
+        params: {
+          bidCost: BigInt(10 ** 6),
+          bidIncrement: BigInt(price / 1000),
+          timerExtensionInSlots: BigInt(12),
+          startTimeInSlots: startSlot,
+          initialDurationInSlots: BigInt(36),
+          timerMode: TimerMode.Cumulative,
+          timingMode: TimingMode.Slots,
+          maxEndTimeInSlots: BigInt(0),
+          buyoutPrice: BigInt(price),
+          reservePrice: BigInt(0),
+          solEscrow: false,
+        },
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+      await waitForSlot(provider.connection, startSlot);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Create Bid Agent Listing tx failed!");
+    }
+  });
+
+  it("User 3 creates a bid agent, it gets cranked and cancelled", async () => {
+    const mint = bidAgentNft.mint;
+    const bidCost = BigInt(10 ** 6);
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedBidAgentListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [bidAgent] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("bid_agent"),
+        listing.toBuffer(),
+        user3.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [ata] = PublicKey.findProgramAddressSync(
+      [
+        user3.publicKey.toBytes(),
+        TOKEN_2022_PROGRAM_ID.toBytes(),
+        sBidMint.publicKey.toBytes(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    const ownerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user3.secretKey)
+    );
+    const crankerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user2.secretKey)
+    );
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      ownerSigner.publicKey
+    );
+
+    const taOld = await fetchToken(umi, fromWeb3JsPublicKey(ata));
+
+    try {
+      await createBidAgent(umi, {
+        owner: ownerSigner,
+        userAccount,
+        bidAgent: fromWeb3JsPublicKey(bidAgent),
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        ownerSbidAta: fromWeb3JsPublicKey(ata),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+        maxPrice: BigInt(LAMPORTS_PER_SOL),
+        maxBids: 1,
+        bidWindowInSlots: BigInt(0),
+        deposit: BigInt(2) * bidCost,
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Create Bid Agent tx failed!");
+    }
+
+    // the deposit is burned from the owner
+    let ta = await fetchToken(umi, fromWeb3JsPublicKey(ata));
+    expect(ta.amount).to.eq(taOld.amount - BigInt(2) * bidCost);
+
+    try {
+      await crankBidAgent(umi, {
+        cranker: crankerSigner,
+        bidAgent: fromWeb3JsPublicKey(bidAgent),
+        userAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Crank Bid Agent tx failed!");
+    }
+
+    const listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+    expect(listingAccount.highestBidder.toString()).to.eq(
+      user3.publicKey.toString()
+    );
+    expect(listingAccount.currentBid).to.eq(listingAccount.bidIncrement);
+
+    const bidAgentAccount = await fetchBidAgent(
+      umi,
+      fromWeb3JsPublicKey(bidAgent)
+    );
+    expect(bidAgentAccount.bidsPlaced).to.eq(1);
+    expect(bidAgentAccount.deposited).to.eq(bidCost);
+
+    // the agent's owner is already the highest bidder
+    try {
+      await crankBidAgent(umi, {
+        cranker: crankerSigner,
+        bidAgent: fromWeb3JsPublicKey(bidAgent),
+        userAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Crank Bid Agent tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6003);
+      expect(errorCode).to.eq("BidderIsHighestBidder");
+    }
+
+    // the agent can't be cancelled against another marketplace's sBid mint
+    const otherSbidMint = anchor.web3.Keypair.generate();
+    const otherMarketplaceName = "Other Marketplace";
+    const [otherMarketplace] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("marketplace"),
+        user3.publicKey.toBuffer(),
+        otherSbidMint.publicKey.toBuffer(),
+        anchor.utils.bytes.utf8.encode(otherMarketplaceName),
+      ],
+      program.programId
+    );
+
+    await initialize(umi, {
+      admin: ownerSigner,
+      sbidMint: createSignerFromKeypair(
+        umi,
+        umi.eddsa.createKeypairFromSecretKey(otherSbidMint.secretKey)
+      ),
+      marketplace: fromWeb3JsPublicKey(otherMarketplace),
+      fee: 0,
+      name: otherMarketplaceName,
+      tokenName: "Other Bid Token",
+      tokenSymbol: "oBid",
+      uri: "",
+      mintCosts: MINT_TIER_COSTS,
+    }).sendAndConfirm(umi, options);
+
+    const otherAta = await createAssociatedTokenAccount(
+      provider.connection,
+      user3,
+      otherSbidMint.publicKey,
+      user3.publicKey,
+      confirmOpts,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    try {
+      await cancelBidAgent(umi, {
+        owner: ownerSigner,
+        bidAgent: fromWeb3JsPublicKey(bidAgent),
+        marketplace: fromWeb3JsPublicKey(otherMarketplace),
+        sbidMint: fromWeb3JsPublicKey(otherSbidMint.publicKey),
+        ownerSbidAta: fromWeb3JsPublicKey(otherAta),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Cancel Bid Agent tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(2001);
+      expect(errorCode).to.eq("ConstraintHasOne");
+    }
+
+    try {
+      await cancelBidAgent(umi, {
+        owner: ownerSigner,
+        bidAgent: fromWeb3JsPublicKey(bidAgent),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        ownerSbidAta: fromWeb3JsPublicKey(ata),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Cancel Bid Agent tx failed!");
+    }
+
+    // the unspent deposit is returned and the agent closed
+    ta = await fetchToken(umi, fromWeb3JsPublicKey(ata));
+    expect(ta.amount).to.eq(taOld.amount - bidCost);
+    expect(await provider.connection.getAccountInfo(bidAgent)).to.eq(null);
+  });
+
+  it("Anyone settles an unpaid auction after the grace period", async () => {
+    const mint = bidAgentNft.mint;
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedBidAgentListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [bidHistory] = PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("bid_history"), listing.toBuffer()],
+      program.programId
+    );
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+    const winnerAta = getAssociatedTokenAddressSync(mint, user3.publicKey);
+
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+
+    const winnerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user3.secretKey)
+    );
+    const crankerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user2.secretKey)
+    );
+    const [winnerAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      winnerSigner.publicKey
+    );
+
+    const settleAccounts = {
+      cranker: crankerSigner,
+      seller: fromWeb3JsPublicKey(user1.publicKey),
+      sellerAta: fromWeb3JsPublicKey(bidAgentNft.ata),
+      winnerAccount,
+      mint: fromWeb3JsPublicKey(mint),
+      listing: fromWeb3JsPublicKey(listing),
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      escrow: fromWeb3JsPublicKey(escrow),
+      metadata,
+      masterEdition: editionAccount,
+      sysvarInstructions: fromWeb3JsPublicKey(
+        anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+      ),
+      amount: BigInt(1),
+    };
+
+    // the winner is still within the default grace period
+    try {
+      await settleListing(umi, settleAccounts).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Settle Listing tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6028);
+      expect(errorCode).to.eq("SettlementGracePeriodActive");
+    }
+
+    await updateMarketplaceSettlementGrace(umi, {
+      admin,
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      graceInSlots: BigInt(0),
+    }).sendAndConfirm(umi, options);
+
+    // We sleep 'till auction end time passes
+    const listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+    while (
+      BigInt(await provider.connection.getSlot("confirmed")) <=
+      listingAccount.endTimeInSlots
+    ) {
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+
+    // the winner can no longer pay once the grace period has expired
+    try {
+      await endListing(umi, {
+        user: winnerSigner,
+        seller: listingAccount.seller,
+        userAccount: winnerAccount,
+        userAta: fromWeb3JsPublicKey(winnerAta),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(bidAgentNft.collection),
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ End Listing tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6029);
+      expect(errorCode).to.eq("SettlementGracePeriodExpired");
+    }
+
+    const winnerOld = await fetchUserAccount(umi, winnerAccount);
+
+    try {
+      await settleListing(umi, settleAccounts).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Settle Listing tx failed!");
+    }
+
+    // the NFT is back with the seller
+    const ta = await fetchToken(umi, fromWeb3JsPublicKey(bidAgentNft.ata));
+    expect(ta.amount).to.eq(BigInt(1));
+
+    expect(await provider.connection.getAccountInfo(listing)).to.eq(null);
+    expect(await provider.connection.getAccountInfo(bidHistory)).to.eq(null);
+    expect(await provider.connection.getAccountInfo(escrow)).to.eq(null);
+
+    // the winner is penalized for not paying
+    const winner = await fetchUserAccount(umi, winnerAccount);
+    expect(winner.totalAuctionsUnpaid).to.eq(1);
+    expect(winner.points).to.eq(Math.max(winnerOld.points - 100, 0));
+  });
+
+  it("Create SOL Escrow Listing", async () => {
+    const mint = escrowNft.mint;
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedEscrowListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+    const startSlot = await getListingStartSlot(provider.connection);
+
+    const sellerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+    );
+
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      sellerSigner.publicKey
+    );
+
+    try {
+      await list(umi, {
+        seller: sellerSigner,
+        admin,
+        userAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(escrowNft.collection),
+        sellerAta: fromWeb3JsPublicKey(escrowNft.ata),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_PROGRAM_ID),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        seed: BigInt(seedEscrowListing.toString()),
+        params: {
+          bidCost: BigInt(10 ** 6),
+          // escrowed bids far below the rent exempt minimum of the vault
+          bidIncrement: BigInt(1),
+          timerExtensionInSlots: BigInt(12),
+          startTimeInSlots: startSlot,
+          initialDurationInSlots: BigInt(36),
+          timerMode: TimerMode.Cumulative,
+          timingMode: TimingMode.Slots,
+          maxEndTimeInSlots: BigInt(0),
+          buyoutPrice: BigInt(0),
+          reservePrice: BigInt(0),
+          solEscrow: true,
+        },
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+      await waitForSlot(provider.connection, startSlot);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Create SOL Escrow Listing tx failed!");
+    }
+
+    const listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+    expect(listingAccount.solEscrow).to.eq(true);
+
+    // the seller funds the rent of the bid vault
+    const [bidVault] = PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("bid_vault"), listing.toBuffer()],
+      program.programId
+    );
+    expect(await provider.connection.getBalance(bidVault)).to.eq(
+      await provider.connection.getMinimumBalanceForRentExemption(0)
+    );
+  });
+
+  it("Bids on a SOL Escrow Listing lock SOL and the winner is settled from the vault", async () => {
+    const mint = escrowNft.mint;
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedEscrowListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [bidVault] = PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("bid_vault"), listing.toBuffer()],
+      program.programId
+    );
+    const [bidHistory] = PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("bid_history"), listing.toBuffer()],
+      program.programId
+    );
+    const [treasury] = PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("treasury"), marketplace.toBuffer()],
+      program.programId
+    );
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+    const vaultRent =
+      await provider.connection.getMinimumBalanceForRentExemption(0);
+
+    const bid = async (u: anchor.web3.Keypair) => {
+      const userSigner = createSignerFromKeypair(
+        umi,
+        umi.eddsa.createKeypairFromSecretKey(u.secretKey)
+      );
+      const [ata] = PublicKey.findProgramAddressSync(
+        [
+          u.publicKey.toBytes(),
+          TOKEN_2022_PROGRAM_ID.toBytes(),
+          sBidMint.publicKey.toBytes(),
+        ],
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      const [userAccount] = fetchUserAccountPDA(
+        umi,
+        programId,
+        fromWeb3JsPublicKey(marketplace),
+        userSigner.publicKey
+      );
+      const listingAccount = await fetchListingV2(
+        umi,
+        fromWeb3JsPublicKey(listing)
+      );
+
+      await placeBid(umi, {
+        bidder: userSigner,
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        userAccount,
+        bidderSbidAta: fromWeb3JsPublicKey(ata),
+        mint: fromWeb3JsPublicKey(mint),
+        listing: fromWeb3JsPublicKey(listing),
+        previousBidder: listingAccount.currentBid
+          ? listingAccount.highestBidder
+          : undefined,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+        highestBidder: listingAccount.highestBidder,
+        currentBid: listingAccount.currentBid,
+      }).sendAndConfirm(umi, options);
+    };
+
+    try {
+      await bid(user2);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Place Bid tx failed!");
+    }
+
+    let listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+    expect(await provider.connection.getBalance(bidVault)).to.eq(
+      vaultRent + Number(listingAccount.currentBid)
+    );
+
+    const user2BalanceOld = await provider.connection.getBalance(
+      user2.publicKey
+    );
+    const firstBid = listingAccount.currentBid;
+
+    try {
+      await bid(user3);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Place Bid tx failed!");
+    }
+
+    // the vault holds the new highest bid and the outbid bidder is refunded
+    listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+    expect(await provider.connection.getBalance(bidVault)).to.eq(
+      vaultRent + Number(listingAccount.currentBid)
+    );
+    expect(await provider.connection.getBalance(user2.publicKey)).to.eq(
+      user2BalanceOld + Number(firstBid)
+    );
+
+    // bid agents can't lock SOL for their bids
+    const ownerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user2.secretKey)
+    );
+    const [ownerAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      ownerSigner.publicKey
+    );
+    const [bidAgent] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("bid_agent"),
+        listing.toBuffer(),
+        user2.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [ownerSbidAta] = PublicKey.findProgramAddressSync(
+      [
+        user2.publicKey.toBytes(),
+        TOKEN_2022_PROGRAM_ID.toBytes(),
+        sBidMint.publicKey.toBytes(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    try {
+      await createBidAgent(umi, {
+        owner: ownerSigner,
+        userAccount: ownerAccount,
+        bidAgent: fromWeb3JsPublicKey(bidAgent),
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        ownerSbidAta: fromWeb3JsPublicKey(ownerSbidAta),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+        maxPrice: BigInt(LAMPORTS_PER_SOL),
+        maxBids: 1,
+        bidWindowInSlots: BigInt(0),
+        deposit: BigInt(10 ** 6),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Create Bid Agent tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6031);
+      expect(errorCode).to.eq("SolEscrowNotSupported");
+    }
+
+    // We sleep 'till auction end time passes, the grace period is already 0
+    while (
+      BigInt(await provider.connection.getSlot("confirmed")) <=
+      listingAccount.endTimeInSlots
+    ) {
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [winnerAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      fromWeb3JsPublicKey(user3.publicKey)
+    );
+    const winnerAta = getAssociatedTokenAddressSync(mint, user3.publicKey);
+    const crankerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user4.secretKey)
+    );
+
+    const winnerOld = await fetchUserAccount(umi, winnerAccount);
+    const treasuryBalanceOld = await provider.connection.getBalance(treasury);
+
+    try {
+      await settleListing(umi, {
+        cranker: crankerSigner,
+        seller: fromWeb3JsPublicKey(user1.publicKey),
+        sellerAta: fromWeb3JsPublicKey(escrowNft.ata),
+        winnerAccount,
+        winner: fromWeb3JsPublicKey(user3.publicKey),
+        winnerAta: fromWeb3JsPublicKey(winnerAta),
+        mint: fromWeb3JsPublicKey(mint),
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Settle SOL Escrow Listing tx failed!");
+    }
+
+    // the winner gets the NFT and the vault pays the treasury and the seller
+    const ta = await fetchToken(umi, fromWeb3JsPublicKey(winnerAta));
+    expect(ta.amount).to.eq(BigInt(1));
+
+    const fee = (listingAccount.currentBid * BigInt(500)) / BigInt(10000);
+    expect(await provider.connection.getBalance(treasury)).to.eq(
+      treasuryBalanceOld + Number(fee)
+    );
+    expect(await provider.connection.getBalance(bidVault)).to.eq(0);
+    expect(await provider.connection.getAccountInfo(listing)).to.eq(null);
+    expect(await provider.connection.getAccountInfo(bidHistory)).to.eq(null);
+
+    // a winner who paid is not penalized
+    const winner = await fetchUserAccount(umi, winnerAccount);
+    expect(winner.totalAuctionsUnpaid).to.eq(winnerOld.totalAuctionsUnpaid);
+  });
+
+  it("Auction ending below the reserve price returns the NFT to the seller", async () => {
+    const price = 2 * LAMPORTS_PER_SOL;
+    const mint = reserveNft.mint;
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedReserveListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+
+    const sellerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+    );
+    const bidderSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user2.secretKey)
+    );
+    const [sellerAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      sellerSigner.publicKey
+    );
+    const [bidderAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      bidderSigner.publicKey
+    );
+    const [bidderSbidAta] = PublicKey.findProgramAddressSync(
+      [
+        user2.publicKey.toBytes(),
+        TOKEN_2022_PROGRAM_ID.toBytes(),
+        sBidMint.publicKey.toBytes(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    // the winner must be able to pay again after the auction end
+    await updateMarketplaceSettlementGrace(umi, {
+      admin,
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      graceInSlots: BigInt(216_000),
+    }).sendAndConfirm(umi, options);
+
+    const startSlot = await getListingStartSlot(provider.connection);
+
+    try {
+      await list(umi, {
+        seller: sellerSigner,
+        admin,
+        userAccount: sellerAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(reserveNft.collection),
+        sellerAta: fromWeb3JsPublicKey(reserveNft.ata),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_PROGRAM_ID),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        seed: BigInt(seedReserveListing.toString()),
+        params: {
+          bidCost: BigInt(10 ** 6),
+          bidIncrement: BigInt(price / 1000),
+          timerExtensionInSlots: BigInt(12),
+          startTimeInSlots: startSlot,
+          initialDurationInSlots: BigInt(24),
+          timerMode: TimerMode.Cumulative,
+          timingMode: TimingMode.Slots,
+          maxEndTimeInSlots: BigInt(0),
+          buyoutPrice: BigInt(0),
+          reservePrice: BigInt(price / 100),
+          solEscrow: false,
+        },
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+      await waitForSlot(provider.connection, startSlot);
+
+      await placeBid(umi, {
+        bidder: bidderSigner,
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        userAccount: bidderAccount,
+        bidderSbidAta: fromWeb3JsPublicKey(bidderSbidAta),
+        mint: fromWeb3JsPublicKey(mint),
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+        highestBidder: fromWeb3JsPublicKey(PublicKey.default),
+        currentBid: BigInt(0),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Create Reserve Listing and bid tx failed!");
+    }
+
+    const listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+    expect(listingAccount.reservePrice).to.eq(BigInt(price / 100));
+    expect(listingAccount.currentBid < listingAccount.reservePrice).to.eq(
+      true
+    );
+
+    // We sleep 'till auction end time passes
+    while (
+      BigInt(await provider.connection.getSlot("confirmed")) <
+      listingAccount.endTimeInSlots
+    ) {
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+
+    const endAccounts = {
+      seller: listingAccount.seller,
+      mint: fromWeb3JsPublicKey(mint),
+      collection: fromWeb3JsPublicKey(reserveNft.collection),
+      listing: fromWeb3JsPublicKey(listing),
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      escrow: fromWeb3JsPublicKey(escrow),
+      metadata,
+      masterEdition: editionAccount,
+      sysvarInstructions: fromWeb3JsPublicKey(
+        anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+      ),
+      amount: BigInt(1),
+    };
+
+    // the highest bidder didn't win
+    try {
+      await endListing(umi, {
+        ...endAccounts,
+        user: bidderSigner,
+        userAccount: bidderAccount,
+        userAta: fromWeb3JsPublicKey(
+          getAssociatedTokenAddressSync(mint, user2.publicKey)
+        ),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ End Listing tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6002);
+      expect(errorCode).to.eq("ClaimerIsNotSeller");
+    }
+
+    const sellerOld = await fetchUserAccount(umi, sellerAccount);
+    const bidderOld = await fetchUserAccount(umi, bidderAccount);
+
+    try {
+      await endListing(umi, {
+        ...endAccounts,
+        user: sellerSigner,
+        userAccount: sellerAccount,
+        userAta: fromWeb3JsPublicKey(reserveNft.ata),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ End Listing below reserve tx failed!");
+    }
+
+    const ta = await fetchToken(umi, fromWeb3JsPublicKey(reserveNft.ata));
+    expect(ta.amount).to.eq(BigInt(1));
+    expect(await provider.connection.getAccountInfo(listing)).to.eq(null);
+
+    // neither side is counted as a winner
+    const seller = await fetchUserAccount(umi, sellerAccount);
+    const bidder = await fetchUserAccount(umi, bidderAccount);
+    expect(seller.totalAuctionsWon).to.eq(sellerOld.totalAuctionsWon);
+    expect(seller.points).to.eq(sellerOld.points);
+    expect(bidder.totalAuctionsWon).to.eq(bidderOld.totalAuctionsWon);
+    expect(bidder.points).to.eq(bidderOld.points);
+  });
+
+  it("Admin updates the bid cost bounds", async () => {
+    try {
+      await updateMarketplaceBidCostBounds(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        minBidCost: BigInt(10 ** 6),
+        maxBidCost: BigInt(0),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Update Bid Cost Bounds tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6035);
+      expect(errorCode).to.eq("InvalidBidCostBounds");
+    }
+
+    const updates = [];
+    const listener = program.addEventListener("marketplaceUpdated", (event) =>
+      updates.push(event.update)
+    );
+
+    try {
+      await updateMarketplaceBidCostBounds(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        minBidCost: BigInt(10 ** 5),
+        maxBidCost: BigInt(3 * 10 ** 6),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Update Bid Cost Bounds tx failed!");
+    }
+
+    const marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.minBidCost).to.eq(BigInt(10 ** 5));
+    expect(marketplaceAccount.maxBidCost).to.eq(BigInt(3 * 10 ** 6));
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    expect(updates.length).to.eq(1);
+    expect(updates[0].bidCostBounds.old.maxBidCost.toNumber()).to.eq(
+      5 * 10 ** 6
+    );
+    expect(updates[0].bidCostBounds.new.maxBidCost.toNumber()).to.eq(
+      3 * 10 ** 6
+    );
+  });
+
+  it("Premium listing bid cost is bounded and burned per bid", async () => {
+    const price = 2 * LAMPORTS_PER_SOL;
+    const mint = reserveNft.mint;
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedPremiumListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+
+    const sellerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+    );
+    const bidderSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user3.secretKey)
+    );
+    const [sellerAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      sellerSigner.publicKey
+    );
+    const [bidderAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      bidderSigner.publicKey
+    );
+    const [bidderSbidAta] = PublicKey.findProgramAddressSync(
+      [
+        user3.publicKey.toBytes(),
+        TOKEN_2022_PROGRAM_ID.toBytes(),
+        sBidMint.publicKey.toBytes(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    const listPremium = async (bidCost: bigint) =>
+      list(umi, {
+        seller: sellerSigner,
+        admin,
+        userAccount: sellerAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(reserveNft.collection),
+        sellerAta: fromWeb3JsPublicKey(reserveNft.ata),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_PROGRAM_ID),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        seed: BigInt(seedPremiumListing.toString()),
+        params: {
+          bidCost,
+          bidIncrement: BigInt(price / 1000),
+          timerExtensionInSlots: BigInt(12),
+          startTimeInSlots: await getListingStartSlot(provider.connection),
+          initialDurationInSlots: BigInt(240),
+          timerMode: TimerMode.Cumulative,
+          timingMode: TimingMode.Slots,
+          maxEndTimeInSlots: BigInt(0),
+          buyoutPrice: BigInt(0),
+          reservePrice: BigInt(0),
+          solEscrow: false,
+        },
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+
+    try {
+      await listPremium(BigInt(5 * 10 ** 6));
+
+      expect.fail("❌ Create Listing tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6034);
+      expect(errorCode).to.eq("InvalidBidCost");
+    }
+
+    const taOld = await fetchToken(umi, fromWeb3JsPublicKey(bidderSbidAta));
+
+    try {
+      await listPremium(BigInt(3 * 10 ** 6));
+
+      const { startTimeInSlots } = await fetchListingV2(
+        umi,
+        fromWeb3JsPublicKey(listing)
+      );
+      await waitForSlot(provider.connection, startTimeInSlots);
+
+      await placeBid(umi, {
+        bidder: bidderSigner,
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        userAccount: bidderAccount,
+        bidderSbidAta: fromWeb3JsPublicKey(bidderSbidAta),
+        mint: fromWeb3JsPublicKey(mint),
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+        highestBidder: fromWeb3JsPublicKey(PublicKey.default),
+        currentBid: BigInt(0),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Create Premium Listing and bid tx failed!");
+    }
+
+    const listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+    expect(listingAccount.bidCost).to.eq(BigInt(3 * 10 ** 6));
+
+    const ta = await fetchToken(umi, fromWeb3JsPublicKey(bidderSbidAta));
+    expect(ta.amount).to.eq(taOld.amount - BigInt(3 * 10 ** 6));
+  });
+
+  it("Bids extend the auction end time according to the timer mode", async () => {
+    const price = 2 * LAMPORTS_PER_SOL;
+    const timerExtension = BigInt(12);
+    const initialDuration = BigInt(240);
+
+    const listWithTimer = async (
+      seller: anchor.web3.Keypair,
+      listedNft: Nft,
+      sellerAta: PublicKey,
+      listingSeed: anchor.BN,
+      timerMode: TimerMode,
+      maxEndOffset: bigint
+    ) => {
+      const [listing] = PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("listing"),
+          marketplace.toBuffer(),
+          listedNft.mint.toBuffer(),
+          listingSeed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const sellerSigner = createSignerFromKeypair(
+        umi,
+        umi.eddsa.createKeypairFromSecretKey(seller.secretKey)
+      );
+      const [userAccount] = fetchUserAccountPDA(
+        umi,
+        programId,
+        fromWeb3JsPublicKey(marketplace),
+        sellerSigner.publicKey
+      );
+      const startSlot = await getListingStartSlot(provider.connection);
+
+      await list(umi, {
+        seller: sellerSigner,
+        admin,
+        userAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        mint: fromWeb3JsPublicKey(listedNft.mint),
+        collection: fromWeb3JsPublicKey(listedNft.collection),
+        sellerAta: fromWeb3JsPublicKey(sellerAta),
+        escrow: fromWeb3JsPublicKey(
+          getAssociatedTokenAddressSync(listedNft.mint, listing, true)
+        ),
+        metadata: findMetadataPda(umi, {
+          mint: fromWeb3JsPublicKey(listedNft.mint),
+        })[0],
+        masterEdition: findMasterEditionPda(umi, {
+          mint: fromWeb3JsPublicKey(listedNft.mint),
+        })[0],
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_PROGRAM_ID),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        seed: BigInt(listingSeed.toString()),
+        params: {
+          bidCost: BigInt(10 ** 6),
+          bidIncrement: BigInt(price / 1000),
+          timerExtensionInSlots: timerExtension,
+          startTimeInSlots: startSlot,
+          initialDurationInSlots: initialDuration,
+          timerMode,
+          timingMode: TimingMode.Slots,
+          maxEndTimeInSlots: startSlot + initialDuration + maxEndOffset,
+          buyoutPrice: BigInt(0),
+          reservePrice: BigInt(0),
+          solEscrow: false,
+        },
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+      await waitForSlot(provider.connection, startSlot);
+
+      return listing;
+    };
+
+    const bid = async (
+      bidder: anchor.web3.Keypair,
+      listedNft: Nft,
+      listing: PublicKey
+    ) => {
+      const bidderSigner = createSignerFromKeypair(
+        umi,
+        umi.eddsa.createKeypairFromSecretKey(bidder.secretKey)
+      );
+      const [userAccount] = fetchUserAccountPDA(
+        umi,
+        programId,
+        fromWeb3JsPublicKey(marketplace),
+        bidderSigner.publicKey
+      );
+      const [bidderSbidAta] = PublicKey.findProgramAddressSync(
+        [
+          bidder.publicKey.toBytes(),
+          TOKEN_2022_PROGRAM_ID.toBytes(),
+          sBidMint.publicKey.toBytes(),
+        ],
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+
+      await placeBid(umi, {
+        bidder: bidderSigner,
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        userAccount,
+        bidderSbidAta: fromWeb3JsPublicKey(bidderSbidAta),
+        mint: fromWeb3JsPublicKey(listedNft.mint),
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+        highestBidder: fromWeb3JsPublicKey(PublicKey.default),
+        currentBid: BigInt(0),
+      }).sendAndConfirm(umi, options);
+    };
+
+    // the max end time of a capped timer can't be before the auction end
+    try {
+      await listWithTimer(
+        user2,
+        delistNft,
+        delistNft.ata,
+        seedCappedTimerListing,
+        TimerMode.Capped,
+        BigInt(-1)
+      );
+
+      expect.fail("❌ Create Listing tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6036);
+      expect(errorCode).to.eq("InvalidTimerMode");
+    }
+
+    try {
+      const resetListing = await listWithTimer(
+        user3,
+        buyNowNft,
+        getAssociatedTokenAddressSync(buyNowNft.mint, user3.publicKey),
+        seedResetTimerListing,
+        TimerMode.ResetToAtLeast,
+        BigInt(0)
+      );
+      const resetOld = await fetchListingV2(
+        umi,
+        fromWeb3JsPublicKey(resetListing)
+      );
+
+      await bid(user2, buyNowNft, resetListing);
+
+      // the end is further away than the extension, so it doesn't move
+      const resetNew = await fetchListingV2(
+        umi,
+        fromWeb3JsPublicKey(resetListing)
+      );
+      expect(resetNew.endTimeInSlots).to.eq(resetOld.endTimeInSlots);
+
+      const cappedListing = await listWithTimer(
+        user2,
+        delistNft,
+        delistNft.ata,
+        seedCappedTimerListing,
+        TimerMode.Capped,
+        BigInt(5)
+      );
+
+      await bid(user3, delistNft, cappedListing);
+
+      // the extension is cut at the max end time
+      const capped = await fetchListingV2(
+        umi,
+        fromWeb3JsPublicKey(cappedListing)
+      );
+      expect(capped.endTimeInSlots).to.eq(capped.maxEndTimeInSlots);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Timer mode listings and bids tx failed!");
+    }
+  });
+
+  it("Listing parameters outside of the marketplace bounds are rejected", async () => {
+    const price = 2 * LAMPORTS_PER_SOL;
+    const mint = timestampNft.mint;
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedTimestampListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    const sellerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+    );
+    const [sellerAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      sellerSigner.publicKey
+    );
+
+    const defaultBounds = (
+      await fetchMarketplace(umi, fromWeb3JsPublicKey(marketplace))
+    ).listingBounds;
+
+    const listWith = async (params: {
+      bidIncrement?: bigint;
+      timerExtensionInSlots?: bigint;
+      startTimeInSlots?: bigint;
+      initialDurationInSlots?: bigint;
+      buyoutPrice?: bigint;
+    }) =>
+      list(umi, {
+        seller: sellerSigner,
+        admin,
+        userAccount: sellerAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(timestampNft.collection),
+        sellerAta: fromWeb3JsPublicKey(timestampNft.ata),
+        escrow: fromWeb3JsPublicKey(
+          getAssociatedTokenAddressSync(mint, listing, true)
+        ),
+        metadata: findMetadataPda(umi, {
+          mint: fromWeb3JsPublicKey(mint),
+        })[0],
+        masterEdition: findMasterEditionPda(umi, {
+          mint: fromWeb3JsPublicKey(mint),
+        })[0],
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_PROGRAM_ID),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        seed: BigInt(seedTimestampListing.toString()),
+        params: {
+          bidCost: BigInt(10 ** 6),
+          bidIncrement: BigInt(price / 1000),
+          timerExtensionInSlots: BigInt(12),
+          startTimeInSlots: await getListingStartSlot(provider.connection),
+          initialDurationInSlots: BigInt(24),
+          timerMode: TimerMode.Cumulative,
+          timingMode: TimingMode.Slots,
+          maxEndTimeInSlots: BigInt(0),
+          buyoutPrice: BigInt(0),
+          reservePrice: BigInt(0),
+          solEscrow: false,
+          ...params,
+        },
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+
+    const expectListError = async (
+      params: Parameters<typeof listWith>[0],
+      expectedNumber: number,
+      expectedCode: string
+    ) => {
+      try {
+        await listWith(params);
+
+        expect.fail("❌ Create Listing tx should have failed!");
+      } catch (err) {
+        const { errorNumber, errorCode } = parseAnchorError(
+          err.transactionLogs
+        );
+
+        expect(errorNumber).to.eq(expectedNumber);
+        expect(errorCode).to.eq(expectedCode);
+      }
+    };
+
+    await expectListError(
+      { bidIncrement: BigInt(0) },
+      6037,
+      "InvalidBidIncrement"
+    );
+    await expectListError(
+      {
+        timerExtensionInSlots:
+          defaultBounds.maxTimerExtensionInSlots + BigInt(1),
+      },
+      6038,
+      "InvalidTimerExtension"
+    );
+    await expectListError(
+      { initialDurationInSlots: BigInt(0) },
+      6039,
+      "InvalidListingDuration"
+    );
+    await expectListError(
+      {
+        startTimeInSlots:
+          BigInt(await provider.connection.getSlot("confirmed")) -
+          BigInt(10),
+      },
+      6040,
+      "InvalidListingStartTime"
+    );
+    await expectListError(
+      { buyoutPrice: BigInt(price / 1000) },
+      6041,
+      "InvalidBuyoutPrice"
+    );
+
+    // the bounds themselves are validated
+    try {
+      await updateMarketplaceListingBounds(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        bounds: { ...defaultBounds, minBidIncrement: BigInt(0) },
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Update Listing Bounds tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6042);
+      expect(errorCode).to.eq("InvalidListingBounds");
+    }
+
+    try {
+      await updateMarketplaceListingBounds(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        bounds: { ...defaultBounds, maxDurationInSlots: BigInt(10) },
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Update Listing Bounds tx failed!");
+    }
+
+    const marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.listingBounds.maxDurationInSlots).to.eq(
+      BigInt(10)
+    );
+
+    await expectListError({}, 6039, "InvalidListingDuration");
+
+    await updateMarketplaceListingBounds(umi, {
+      admin,
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      bounds: defaultBounds,
+    }).sendAndConfirm(umi, options);
+  });
+
+  it("Unix timestamp listings are timed in seconds", async () => {
+    const price = 2 * LAMPORTS_PER_SOL;
+    const mint = timestampNft.mint;
+    const timerExtension = BigInt(2);
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedTimestampListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+
+    const sellerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+    );
+    const bidderSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user2.secretKey)
+    );
+    const [sellerAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      sellerSigner.publicKey
+    );
+    const [bidderAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      bidderSigner.publicKey
+    );
+    const [bidderSbidAta] = PublicKey.findProgramAddressSync(
+      [
+        user2.publicKey.toBytes(),
+        TOKEN_2022_PROGRAM_ID.toBytes(),
+        sBidMint.publicKey.toBytes(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    const blockTime = async () =>
+      BigInt(
+        await provider.connection.getBlockTime(
+          await provider.connection.getSlot("confirmed")
+        )
+      );
+
+    try {
+      await list(umi, {
+        seller: sellerSigner,
+        admin,
+        userAccount: sellerAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(timestampNft.collection),
+        sellerAta: fromWeb3JsPublicKey(timestampNft.ata),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_PROGRAM_ID),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        seed: BigInt(seedTimestampListing.toString()),
+        params: {
+          bidCost: BigInt(10 ** 6),
+          bidIncrement: BigInt(price / 1000),
+          timerExtensionInSlots: timerExtension,
+          startTimeInSlots: (await blockTime()) + BigInt(3),
+          initialDurationInSlots: BigInt(6),
+          timerMode: TimerMode.Cumulative,
+          timingMode: TimingMode.UnixTimestamp,
+          maxEndTimeInSlots: BigInt(0),
+          buyoutPrice: BigInt(0),
+          reservePrice: BigInt(0),
+          solEscrow: false,
+        },
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Create Unix Timestamp Listing tx failed!");
+    }
+
+    const listingOld = await fetchListingV2(umi, fromWeb3JsPublicKey(listing));
+    expect(listingOld.timingMode).to.eq(TimingMode.UnixTimestamp);
+
+    // the listing starts in the future, in seconds
+    while ((await blockTime()) < listingOld.startTimeInSlots) {
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+
+    try {
+      await placeBid(umi, {
+        bidder: bidderSigner,
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        userAccount: bidderAccount,
+        bidderSbidAta: fromWeb3JsPublicKey(bidderSbidAta),
+        mint: fromWeb3JsPublicKey(mint),
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+        highestBidder: fromWeb3JsPublicKey(PublicKey.default),
+        currentBid: BigInt(0),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Bid on Unix Timestamp Listing tx failed!");
+    }
+
+    // the extension is added in seconds
+    const listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+    expect(listingAccount.endTimeInSlots).to.eq(
+      listingOld.endTimeInSlots + timerExtension
+    );
+
+    const endAccounts = {
+      user: bidderSigner,
+      userAccount: bidderAccount,
+      seller: listingAccount.seller,
+      userAta: fromWeb3JsPublicKey(
+        getAssociatedTokenAddressSync(mint, user2.publicKey)
+      ),
+      mint: fromWeb3JsPublicKey(mint),
+      collection: fromWeb3JsPublicKey(timestampNft.collection),
+      listing: fromWeb3JsPublicKey(listing),
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      escrow: fromWeb3JsPublicKey(escrow),
+      metadata,
+      masterEdition: editionAccount,
+      sysvarInstructions: fromWeb3JsPublicKey(
+        anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+      ),
+      amount: BigInt(1),
+    };
+
+    // the end time is in seconds, it is far away from the current slot
+    try {
+      await endListing(umi, endAccounts).sendAndConfirm(umi, options);
+
+      expect.fail("❌ End Listing tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6007);
+      expect(errorCode).to.eq("AuctionNotEnded");
+    }
+
+    // We sleep 'till auction end time passes
+    while ((await blockTime()) < listingAccount.endTimeInSlots) {
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+
+    try {
+      await endListing(umi, endAccounts).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ End Unix Timestamp Listing tx failed!");
+    }
+
+    const ta = await fetchToken(
+      umi,
+      fromWeb3JsPublicKey(getAssociatedTokenAddressSync(mint, user2.publicKey))
+    );
+    expect(ta.amount).to.eq(BigInt(1));
+    expect(await provider.connection.getAccountInfo(listing)).to.eq(null);
+  });
+
+  it("Initializer mints sBid Tokens repeatedly with mixed tiers", async () => {
+    const userSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(initializer.secretKey)
+    );
+    const [ata] = PublicKey.findProgramAddressSync(
+      [
+        initializer.publicKey.toBytes(),
+        TOKEN_2022_PROGRAM_ID.toBytes(),
+        sBidMint.publicKey.toBytes(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      userSigner.publicKey
+    );
+
+    // first purchase creates the ATA, the next ones reuse it
+    expect(await provider.connection.getAccountInfo(ata)).to.eq(null);
+
+    let expectedAmount = BigInt(0);
+
+    for (const tier of [
+      MintCostTier.Tier1,
+      MintCostTier.Tier1,
+      MintCostTier.Tier2,
+    ]) {
+      try {
+        await mintBidToken(umi, {
+          admin,
+          userAccount,
+          user: userSigner,
+          marketplace: fromWeb3JsPublicKey(marketplace),
+          sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+          userSbidAta: fromWeb3JsPublicKey(ata),
+          tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+          tier,
+        }).sendAndConfirm(umi, options);
+      } catch (err) {
+        console.error(err);
+        expect.fail("❌ Mint sBid Token tx failed!");
+      }
+
+      expectedAmount +=
+        MINT_TIER_COSTS[tier].amount + MINT_TIER_COSTS[tier].bonus;
+
+      const ta = await fetchToken(umi, fromWeb3JsPublicKey(ata));
+      expect(ta.amount).to.eq(expectedAmount);
+    }
+  });
+
+  it("Admin gated sBid purchases require the admin co-signature", async () => {
+    const userSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(initializer.secretKey)
+    );
+    const [ata] = PublicKey.findProgramAddressSync(
+      [
+        initializer.publicKey.toBytes(),
+        TOKEN_2022_PROGRAM_ID.toBytes(),
+        sBidMint.publicKey.toBytes(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      userSigner.publicKey
+    );
+    const mintAccounts = {
+      userAccount,
+      user: userSigner,
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+      userSbidAta: fromWeb3JsPublicKey(ata),
+      tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+      tier: MintCostTier.Tier1,
+    };
+
+    await updateMarketplaceMintGate(umi, {
+      admin,
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      mintRequiresAdmin: true,
+    }).sendAndConfirm(umi, options);
+
+    let marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.mintRequiresAdmin).to.eq(true);
+
+    try {
+      await mintBidToken(umi, mintAccounts).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Mint sBid Token tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6027);
+      expect(errorCode).to.eq("AdminSignatureRequired");
+    }
+
+    try {
+      await mintBidToken(umi, { ...mintAccounts, admin }).sendAndConfirm(
+        umi,
+        options
+      );
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Admin co-signed Mint sBid Token tx failed!");
+    }
+
+    await updateMarketplaceMintGate(umi, {
+      admin,
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      mintRequiresAdmin: false,
+    }).sendAndConfirm(umi, options);
+
+    marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.mintRequiresAdmin).to.eq(false);
+
+    // purchases are permissionless again
+    const taOld = await fetchToken(umi, fromWeb3JsPublicKey(ata));
+
+    try {
+      await mintBidToken(umi, mintAccounts).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Permissionless Mint sBid Token tx failed!");
+    }
+
+    const ta = await fetchToken(umi, fromWeb3JsPublicKey(ata));
+    expect(ta.amount).to.eq(
+      taOld.amount +
+        MINT_TIER_COSTS[MintCostTier.Tier1].amount +
+        MINT_TIER_COSTS[MintCostTier.Tier1].bonus
+    );
+  });
+
+  it("User 4 sets bid limits", async () => {
+    const userSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user4.secretKey)
+    );
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      userSigner.publicKey
+    );
+
+    const limits = {
+      period: LimitPeriod.Daily,
+      maxBids: 5,
+      maxSpent: MINT_TIER_COSTS[MintCostTier.Tier1].cost,
+    };
+
+    try {
+      await setBidLimits(umi, {
+        user: userSigner,
+        userAccount,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        limits,
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Set Bid Limits tx failed!");
+    }
+
+    const user = await fetchUserAccount(umi, userAccount);
+    expect(user.bidLimits).deep.eq(limits);
+    expect(user.bidsInWindow).to.eq(0);
+    expect(user.spentInWindow).to.eq(BigInt(0));
+  });
+
+  it("User 4 mints over the spending limit - should fail!", async () => {
+    const userSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user4.secretKey)
+    );
+    const [ata] = PublicKey.findProgramAddressSync(
+      [
+        user4.publicKey.toBytes(),
+        TOKEN_2022_PROGRAM_ID.toBytes(),
+        sBidMint.publicKey.toBytes(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      userSigner.publicKey
+    );
+
+    try {
+      await mintBidToken(umi, {
+        admin,
+        userAccount,
+        user: userSigner,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        userSbidAta: fromWeb3JsPublicKey(ata),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+        tier: MintCostTier.Tier2,
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Mint sBid Token tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6019);
+      expect(errorCode).to.eq("SpendLimitReached");
+    }
+  });
+
+  it("User 4 lifts the bid limits, they only apply after the window", async () => {
+    const userSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user4.secretKey)
+    );
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      userSigner.publicKey
+    );
+    const userOld = await fetchUserAccount(umi, userAccount);
+
+    const looserLimits = {
+      period: LimitPeriod.Daily,
+      maxBids: 0,
+      maxSpent: BigInt(0),
+    };
+
+    await setBidLimits(umi, {
+      user: userSigner,
+      userAccount,
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      limits: looserLimits,
+    }).sendAndConfirm(umi, options);
+
+    let user = await fetchUserAccount(umi, userAccount);
+    expect(user.bidLimits).deep.eq(userOld.bidLimits);
+    expect(user.pendingBidLimits).deep.eq(some(looserLimits));
+
+    // tighter limits apply at once and drop the pending ones
+    const tighterLimits = { ...userOld.bidLimits, maxBids: 4 };
+
+    await setBidLimits(umi, {
+      user: userSigner,
+      userAccount,
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      limits: tighterLimits,
+    }).sendAndConfirm(umi, options);
+
+    user = await fetchUserAccount(umi, userAccount);
+    expect(user.bidLimits).deep.eq(tighterLimits);
+    expect(user.pendingBidLimits).deep.eq(none());
+    expect(user.spentInWindow).to.eq(userOld.spentInWindow);
+  });
+
+  it("User 4 self excludes and cannot shorten the exclusion", async () => {
+    const userSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user4.secretKey)
+    );
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      userSigner.publicKey
+    );
+
+    try {
+      await selfExclude(umi, {
+        user: userSigner,
+        userAccount,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        period: ExclusionPeriod.Week,
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Self Exclude tx failed!");
+    }
+
+    const userExcluded = await fetchUserAccount(umi, userAccount);
+    expect(userExcluded.excludedUntil > BigInt(0)).to.eq(true);
+
+    try {
+      await selfExclude(umi, {
+        user: userSigner,
+        userAccount,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        period: ExclusionPeriod.Day,
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Self Exclude tx failed!");
+    }
+
+    const user = await fetchUserAccount(umi, userAccount);
+    expect(user.excludedUntil).to.eq(userExcluded.excludedUntil);
+  });
+
+  it("Self excluded User 4 mints sBid tokens - should fail!", async () => {
+    const userSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user4.secretKey)
+    );
+    const [ata] = PublicKey.findProgramAddressSync(
+      [
+        user4.publicKey.toBytes(),
+        TOKEN_2022_PROGRAM_ID.toBytes(),
+        sBidMint.publicKey.toBytes(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    const [userAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      userSigner.publicKey
+    );
+
+    try {
+      await mintBidToken(umi, {
+        admin,
+        userAccount,
+        user: userSigner,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        userSbidAta: fromWeb3JsPublicKey(ata),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+        tier: MintCostTier.Tier1,
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Mint sBid Token tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6020);
+      expect(errorCode).to.eq("UserSelfExcluded");
+    }
+  });
+
+  it("Update Happy Hour", async () => {
+    const happyHour: HappyHour = {
+      windows: [
+        { startSecondOfDay: 18 * 3600, endSecondOfDay: 19 * 3600 },
+        { startSecondOfDay: 23 * 3600, endSecondOfDay: 1 * 3600 },
+        { startSecondOfDay: 0, endSecondOfDay: 0 },
+      ],
+      incrementMultiplierBps: 5000,
+      extensionMultiplierBps: 20000,
+    };
+
+    try {
+      await updateMarketplaceHappyHour(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        happyHour,
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error("Update Happy Hour Error:", err);
+      expect.fail("❌ Update Happy Hour Tx Failed");
+    }
+
+    const marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.happyHour).deep.equal(happyHour);
+  });
+
+  it("Update Happy Hour with a shorter timer extension - should fail!", async () => {
+    try {
+      await updateMarketplaceHappyHour(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        happyHour: {
+          windows: [
+            { startSecondOfDay: 18 * 3600, endSecondOfDay: 19 * 3600 },
+            { startSecondOfDay: 0, endSecondOfDay: 0 },
+            { startSecondOfDay: 0, endSecondOfDay: 0 },
+          ],
+          incrementMultiplierBps: 5000,
+          extensionMultiplierBps: 5000,
+        },
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Update Happy Hour tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6021);
+      expect(errorCode).to.eq("InvalidHappyHour");
+    }
+  });
+
+  it("Admin withdraws from treasury", async () => {
+    const [treasury] = umi.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([116, 114, 101, 97, 115, 117, 114, 121])
+      ),
+      publicKeySerializer().serialize(fromWeb3JsPublicKey(marketplace)),
+    ]);
+
+    const destination = anchor.web3.Keypair.generate().publicKey;
+    const amount = 0.5 * LAMPORTS_PER_SOL;
+
+    const treasuryBalanceOld = await provider.connection.getBalance(
+      toWeb3JsPublicKey(treasury)
+    );
+
+    try {
+      await withdrawTreasury(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        treasury,
+        destination: fromWeb3JsPublicKey(destination),
+        amount: BigInt(amount),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Withdraw Treasury tx failed!");
+    }
+
+    const treasuryBalanceNew = await provider.connection.getBalance(
+      toWeb3JsPublicKey(treasury)
+    );
+    expect(treasuryBalanceNew).to.equal(treasuryBalanceOld - amount);
+
+    const destinationBalance = await provider.connection.getBalance(
+      destination
+    );
+    expect(destinationBalance).to.equal(amount);
+  });
+
+  it("Admin withdraws the whole treasury balance - should fail!", async () => {
+    const [treasury] = umi.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([116, 114, 101, 97, 115, 117, 114, 121])
+      ),
+      publicKeySerializer().serialize(fromWeb3JsPublicKey(marketplace)),
+    ]);
+
+    const treasuryBalance = await provider.connection.getBalance(
+      toWeb3JsPublicKey(treasury)
+    );
+
+    try {
+      await withdrawTreasury(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        treasury,
+        destination: admin.publicKey,
+        amount: BigInt(treasuryBalance),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Withdraw Treasury tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6016);
+      expect(errorCode).to.eq("InsufficientTreasuryBalance");
+    }
+  });
+
+  it("Admin updates the marketplace fee and sBid metadata", async () => {
+    try {
+      await updateMarketplaceFee(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        fee: 1001,
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Update Fee tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6048);
+      expect(errorCode).to.eq("InvalidMarketplaceFee");
+    }
+
+    const updates = [];
+    const listener = program.addEventListener("marketplaceUpdated", (event) =>
+      updates.push(event.update)
+    );
+
+    try {
+      await updateMarketplaceFee(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        fee: 1000,
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Update Fee tx failed!");
+    }
+
+    let marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.fee).to.eq(1000);
+
+    await updateMarketplaceFee(umi, {
+      admin,
+      marketplace: fromWeb3JsPublicKey(marketplace),
+      fee: 500,
+    }).sendAndConfirm(umi, options);
+
+    // a longer uri grows the metadata stored in the mint
+    const tokenName = "Sandcastle Bid";
+    const tokenSymbol = "SBID";
+    const tokenUri = "https://example.com/sandcastle/sbid/metadata.json";
+
+    try {
+      await updateMarketplaceTokenMetadata(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        name: tokenName,
+        symbol: tokenSymbol,
+        uri: tokenUri,
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Update Token Metadata tx failed!");
+    }
+
+    const metadata = await getTokenMetadata(
+      provider.connection,
+      sBidMint.publicKey,
+      "confirmed"
+    );
+    expect(metadata.name).to.eq(tokenName);
+    expect(metadata.symbol).to.eq(tokenSymbol);
+    expect(metadata.uri).to.eq(tokenUri);
+    expect(metadata.updateAuthority.toString()).to.eq(marketplace.toString());
+
+    // only the admin can update the metadata
+    try {
+      await updateMarketplaceTokenMetadata(umi, {
+        admin: createSignerFromKeypair(
+          umi,
+          umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+        ),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        name: "Not Sandcastle",
+        symbol: tokenSymbol,
+        uri: tokenUri,
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Update Token Metadata tx should have failed!");
+    } catch (err) {
+      const { errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorCode).to.eq("ConstraintHasOne");
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    expect(updates.length).to.eq(3);
+    expect(updates[0].fee.old).to.eq(500);
+    expect(updates[0].fee.new).to.eq(1000);
+    expect(updates[2].tokenMetadata.old.symbol).to.eq("sBid");
+    expect(updates[2].tokenMetadata.new.uri).to.eq(tokenUri);
+
+    marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.fee).to.eq(500);
+  });
+
+  it("Admin hands the marketplace over in two steps", async () => {
+    const newAdmin = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user1.secretKey)
+    );
+    const otherUser = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user2.secretKey)
+    );
+
+    try {
+      await proposeAdmin(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        newAdmin: newAdmin.publicKey,
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Propose Admin tx failed!");
+    }
+
+    let marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.pendingAdmin).to.eq(newAdmin.publicKey);
+    expect(marketplaceAccount.admin).to.eq(admin.publicKey);
+
+    // only the proposed admin can accept
+    try {
+      await acceptAdmin(umi, {
+        pendingAdmin: otherUser,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Accept Admin tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6043);
+      expect(errorCode).to.eq("InvalidPendingAdmin");
+    }
+
+    try {
+      await acceptAdmin(umi, {
+        pendingAdmin: newAdmin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Accept Admin tx failed!");
+    }
+
+    marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.admin).to.eq(newAdmin.publicKey);
+    expect(marketplaceAccount.pendingAdmin).to.eq(
+      fromWeb3JsPublicKey(PublicKey.default)
+    );
+    // the marketplace address is still derived from the creator
+    expect(marketplaceAccount.creator).to.eq(admin.publicKey);
+
+    // the previous admin can't govern the marketplace anymore
+    try {
+      await updateMarketplaceHappyHour(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        happyHour: marketplaceAccount.happyHour,
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Update Happy Hour tx should have failed!");
+    } catch (err) {
+      const { errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorCode).to.eq("ConstraintHasOne");
+    }
+
+    // the new admin governs it, then hands it back
+    try {
+      await updateMarketplaceHappyHour(umi, {
+        admin: newAdmin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        happyHour: marketplaceAccount.happyHour,
+      }).sendAndConfirm(umi, options);
+
+      await proposeAdmin(umi, {
+        admin: newAdmin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        newAdmin: admin.publicKey,
+      }).sendAndConfirm(umi, options);
+
+      await acceptAdmin(umi, {
+        pendingAdmin: admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+      }).sendAndConfirm(umi, options);
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ New admin txs failed!");
+    }
+
+    marketplaceAccount = await fetchMarketplace(
+      umi,
+      fromWeb3JsPublicKey(marketplace)
+    );
+    expect(marketplaceAccount.admin).to.eq(admin.publicKey);
+  });
+
+  it("Admin pauses the marketplace and running auctions are credited the paused time", async () => {
+    const price = 2 * LAMPORTS_PER_SOL;
+    const timerExtension = BigInt(12);
+    const mint = timestampNft.mint;
+    const sellerAta = getAssociatedTokenAddressSync(mint, user2.publicKey);
+
+    const [listing] = PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("listing"),
+        marketplace.toBuffer(),
+        mint.toBuffer(),
+        seedPausedListing.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const escrow = getAssociatedTokenAddressSync(mint, listing, true);
+    const [metadata] = findMetadataPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+    const [editionAccount] = findMasterEditionPda(umi, {
+      mint: fromWeb3JsPublicKey(mint),
+    });
+
+    const sellerSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user2.secretKey)
+    );
+    const bidderSigner = createSignerFromKeypair(
+      umi,
+      umi.eddsa.createKeypairFromSecretKey(user3.secretKey)
+    );
+    const [sellerAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      sellerSigner.publicKey
+    );
+    const [bidderAccount] = fetchUserAccountPDA(
+      umi,
+      programId,
+      fromWeb3JsPublicKey(marketplace),
+      bidderSigner.publicKey
+    );
+    const [bidderSbidAta] = PublicKey.findProgramAddressSync(
+      [
+        user3.publicKey.toBytes(),
+        TOKEN_2022_PROGRAM_ID.toBytes(),
+        sBidMint.publicKey.toBytes(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    const pause = (paused: {
+      listing?: boolean;
+      bidding?: boolean;
+      minting?: boolean;
+      settlement?: boolean;
+    }) =>
+      setPause(umi, {
+        admin,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        paused: {
+          listing: false,
+          bidding: false,
+          minting: false,
+          settlement: false,
+          ...paused,
+        },
+      }).sendAndConfirm(umi, options);
+
+    const listPaused = async () =>
+      list(umi, {
+        seller: sellerSigner,
+        admin,
+        userAccount: sellerAccount,
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(timestampNft.collection),
+        sellerAta: fromWeb3JsPublicKey(sellerAta),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_PROGRAM_ID),
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        seed: BigInt(seedPausedListing.toString()),
+        params: {
+          bidCost: BigInt(10 ** 6),
+          bidIncrement: BigInt(price / 1000),
+          timerExtensionInSlots: timerExtension,
+          startTimeInSlots: await getListingStartSlot(provider.connection),
+          initialDurationInSlots: BigInt(240),
+          timerMode: TimerMode.Cumulative,
+          timingMode: TimingMode.Slots,
+          maxEndTimeInSlots: BigInt(0),
+          buyoutPrice: BigInt(0),
+          reservePrice: BigInt(0),
+          solEscrow: false,
+        },
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+
+    const bid = async () => {
+      const { highestBidder, currentBid } = await fetchListingV2(
+        umi,
+        fromWeb3JsPublicKey(listing)
+      );
+
+      return placeBid(umi, {
+        bidder: bidderSigner,
+        sbidMint: fromWeb3JsPublicKey(sBidMint.publicKey),
+        userAccount: bidderAccount,
+        bidderSbidAta: fromWeb3JsPublicKey(bidderSbidAta),
+        mint: fromWeb3JsPublicKey(mint),
+        listing: fromWeb3JsPublicKey(listing),
+        // listings without sol_escrow don't need their bid vault
+        bidVault: programId,
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        tokenProgram: fromWeb3JsPublicKey(TOKEN_2022_PROGRAM_ID),
+        highestBidder,
+        currentBid,
+      }).sendAndConfirm(umi, options);
+    };
+
+    // listing is paused
+    await pause({ listing: true });
+
+    try {
+      await listPaused();
+
+      expect.fail("❌ Create Listing tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6044);
+      expect(errorCode).to.eq("ListingPaused");
+    }
+
+    await pause({});
+
+    try {
+      await listPaused();
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Create Listing tx failed!");
+    }
+
+    const listingOld = await fetchListingV2(umi, fromWeb3JsPublicKey(listing));
+    await waitForSlot(provider.connection, listingOld.startTimeInSlots);
+
+    // bidding is paused while the auction runs
+    await pause({ bidding: true });
+
+    try {
+      await bid();
+
+      expect.fail("❌ Place Bid tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6045);
+      expect(errorCode).to.eq("BiddingPaused");
+    }
+
+    // buying now is paused along with bidding
+    try {
+      await purchase(umi, {
+        buyer: bidderSigner,
+        seller: fromWeb3JsPublicKey(user2.publicKey),
+        buyerAta: fromWeb3JsPublicKey(
+          getAssociatedTokenAddressSync(mint, user3.publicKey)
+        ),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(timestampNft.collection),
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ Purchase tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6045);
+      expect(errorCode).to.eq("BiddingPaused");
+    }
+
+    const pausedAt = (
+      await fetchMarketplace(umi, fromWeb3JsPublicKey(marketplace))
+    ).biddingPause.startedAt.slots;
+    await waitForSlot(provider.connection, pausedAt + BigInt(10));
+
+    await pause({});
+
+    const { lastDuration } = (
+      await fetchMarketplace(umi, fromWeb3JsPublicKey(marketplace))
+    ).biddingPause;
+    expect(lastDuration.slots >= BigInt(10)).to.eq(true);
+
+    try {
+      await bid();
+    } catch (err) {
+      console.error(err);
+      expect.fail("❌ Place Bid tx failed!");
+    }
+
+    // the paused time was credited along with the bid extension
+    const listingAccount = await fetchListingV2(
+      umi,
+      fromWeb3JsPublicKey(listing)
+    );
+    expect(listingAccount.endTimeInSlots).to.eq(
+      listingOld.endTimeInSlots + lastDuration.slots + timerExtension
+    );
+
+    // settlement is paused
+    await pause({ settlement: true });
+
+    try {
+      await endListing(umi, {
+        user: bidderSigner,
+        userAccount: bidderAccount,
+        seller: listingAccount.seller,
+        userAta: fromWeb3JsPublicKey(
+          getAssociatedTokenAddressSync(mint, user3.publicKey)
+        ),
+        mint: fromWeb3JsPublicKey(mint),
+        collection: fromWeb3JsPublicKey(timestampNft.collection),
+        listing: fromWeb3JsPublicKey(listing),
+        marketplace: fromWeb3JsPublicKey(marketplace),
+        escrow: fromWeb3JsPublicKey(escrow),
+        metadata,
+        masterEdition: editionAccount,
+        sysvarInstructions: fromWeb3JsPublicKey(
+          anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+        ),
+        amount: BigInt(1),
+      }).sendAndConfirm(umi, options);
+
+      expect.fail("❌ End Listing tx should have failed!");
+    } catch (err) {
+      const { errorNumber, errorCode } = parseAnchorError(err.transactionLogs);
+
+      expect(errorNumber).to.eq(6047);
+      expect(errorCode).to.eq("SettlementPaused");
+    }
+
+    await pause({});
+  });
 });
 
 // Helpers
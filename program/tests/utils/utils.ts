@@ -57,3 +57,18 @@ export function fetchUserAccountPDA(
     publicKeySerializer().serialize(user),
   ]);
 }
+
+// Listings can't start in the past, leave room for the list tx to land
+export const LISTING_START_DELAY_IN_SLOTS = 4;
+
+export async function getListingStartSlot(connection: web3.Connection) {
+  return BigInt(
+    (await connection.getSlot("processed")) + LISTING_START_DELAY_IN_SLOTS
+  );
+}
+
+export async function waitForSlot(connection: web3.Connection, slot: bigint) {
+  while (BigInt(await connection.getSlot("confirmed")) < slot) {
+    await new Promise((resolve) => setTimeout(resolve, 400));
+  }
+}